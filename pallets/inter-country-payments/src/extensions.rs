@@ -0,0 +1,212 @@
+//! Transaction validation for government-only chains and urgent payments
+
+use super::*;
+use frame_support::traits::IsSubType;
+use sp_runtime::{
+    traits::{DispatchInfoOf, SignedExtension},
+    transaction_validity::{
+        InvalidTransaction, TransactionPriority, TransactionValidity, TransactionValidityError,
+        ValidTransaction,
+    },
+};
+use sp_std::marker::PhantomData;
+
+/// Rejects signed transactions from accounts that are neither a government, a signatory of a
+/// government, nor on the `AllowedCallers` list, before they enter the transaction pool.
+///
+/// Calls matched by `Config::ExemptCalls` are admitted from any signer. Add this extension to
+/// the runtime's `SignedExtra` to make the chain government-only.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckGovernmentCaller<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckGovernmentCaller<T> {
+    /// Create the extension
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Config + Send + Sync> Default for CheckGovernmentCaller<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for CheckGovernmentCaller<T> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        write!(f, "CheckGovernmentCaller")
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckGovernmentCaller<T> {
+    const IDENTIFIER: &'static str = "CheckGovernmentCaller";
+    type AccountId = T::AccountId;
+    type Call = <T as frame_system::Config>::RuntimeCall;
+    type AdditionalSigned = ();
+    type Pre = ();
+
+    fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+        Ok(())
+    }
+
+    fn validate(
+        &self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        _info: &DispatchInfoOf<Self::Call>,
+        _len: usize,
+    ) -> TransactionValidity {
+        if T::ExemptCalls::contains(call) || Pallet::<T>::is_permitted_caller(who) {
+            Ok(ValidTransaction::default())
+        } else {
+            Err(InvalidTransaction::BadSigner.into())
+        }
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> Result<(), TransactionValidityError> {
+        self.validate(who, call, info, len).map(|_| ())
+    }
+}
+
+/// Raises the transaction pool priority of calls that initiate, sign, execute or retry
+/// `EmergencyAssistance` payments of `High` or `Critical` urgency.
+///
+/// `Critical` payments receive `Config::UrgentPaymentPriority` on top of the priority given by
+/// the other extensions and `High` payments half of it. The boost is only granted when the
+/// signer holds the role the call needs for that payment and the payment is in the status the
+/// call acts on, so declaring an emergency does not let arbitrary accounts or calls that would
+/// fail jump the queue.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct PrioritizeUrgentPayments<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> PrioritizeUrgentPayments<T> {
+    /// Create the extension
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// Priority boost for a payment purpose
+    fn boost(purpose: &PaymentPurpose) -> TransactionPriority {
+        match purpose {
+            PaymentPurpose::EmergencyAssistance { urgency_level: UrgencyLevel::Critical, .. } => {
+                T::UrgentPaymentPriority::get()
+            }
+            PaymentPurpose::EmergencyAssistance { urgency_level: UrgencyLevel::High, .. } => {
+                T::UrgentPaymentPriority::get() / 2
+            }
+            _ => 0,
+        }
+    }
+
+    /// Priority boost `who` earns with `call`, zero unless it may act on the payment
+    fn priority(who: &T::AccountId, call: &Call<T>) -> TransactionPriority {
+        let holds = |government: &T::AccountId, role: SignatoryRole| {
+            Pallet::<T>::government_info(government).is_some_and(|gov| gov.has_role(who, &role))
+        };
+        match call {
+            Call::initiate_payment { from_government, purpose, .. }
+            | Call::initiate_payment_to_country { from_government, purpose, .. }
+                if holds(from_government, SignatoryRole::Initiator) =>
+            {
+                Self::boost(purpose)
+            }
+            Call::sign_payment { payment_id, .. } => match Pallet::<T>::payment_info(payment_id) {
+                Some(payment)
+                    if payment.status == PaymentStatus::Pending
+                        && Pallet::<T>::government_info(&payment.from_government)
+                            .is_some_and(|gov| Pallet::<T>::may_sign(&gov, &payment, who)) =>
+                {
+                    Self::boost(&payment.purpose)
+                }
+                _ => 0,
+            },
+            Call::execute_payment { payment_id } => match Pallet::<T>::payment_info(payment_id) {
+                Some(payment)
+                    if payment.status == PaymentStatus::Authorized
+                        && holds(&payment.from_government, SignatoryRole::Releaser) =>
+                {
+                    Self::boost(&payment.purpose)
+                }
+                _ => 0,
+            },
+            Call::retry_payment { payment_id } => match Pallet::<T>::payment_info(payment_id) {
+                Some(payment)
+                    if payment.status == PaymentStatus::Failed
+                        && holds(&payment.from_government, SignatoryRole::Releaser) =>
+                {
+                    Self::boost(&payment.purpose)
+                }
+                _ => 0,
+            },
+            _ => 0,
+        }
+    }
+}
+
+impl<T: Config + Send + Sync> Default for PrioritizeUrgentPayments<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for PrioritizeUrgentPayments<T> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        write!(f, "PrioritizeUrgentPayments")
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T: Config + Send + Sync> SignedExtension for PrioritizeUrgentPayments<T>
+where
+    <T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+{
+    const IDENTIFIER: &'static str = "PrioritizeUrgentPayments";
+    type AccountId = T::AccountId;
+    type Call = <T as frame_system::Config>::RuntimeCall;
+    type AdditionalSigned = ();
+    type Pre = ();
+
+    fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+        Ok(())
+    }
+
+    fn validate(
+        &self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        _info: &DispatchInfoOf<Self::Call>,
+        _len: usize,
+    ) -> TransactionValidity {
+        let priority = call.is_sub_type().map_or(0, |call| Self::priority(who, call));
+        Ok(ValidTransaction { priority, ..Default::default() })
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> Result<(), TransactionValidityError> {
+        self.validate(who, call, info, len).map(|_| ())
+    }
+}
@@ -8,42 +8,95 @@
 //! - Transparent bilateral payment settlement
 //! - Real-time cross-border transfers
 //! - Compliance and audit trail
+//! - Registered international organisations as contribution recipients
+//! - Registration and status changes governed by a supermajority of Active governments
+//! - Self-service membership applications voted on by Active governments
+//! - Bilateral counterparty allow/deny lists managed by each government's multisig
+//! - Per-government spending limits with rolling daily, monthly and corridor caps
+//! - Amount-tiered approval thresholds
+//! - Role-typed signatories with separation of duties
+//! - Keyless government accounts controlled only through their signatories
+//! - Signatory-approved account rotation with a governance veto window, re-keying only payments
+//!   still in flight
+//! - Several institutions per country, looked up by country code and institution type
+//! - ISO 3166-1 alpha-3 country codes checked against a governance-managed list
+//! - Government profile updates (LEI, BIC, website hash) with an on-chain version history
+//! - Orderly deregistration that settles in-flight payments and archives a history summary
+//! - In-flight payments frozen on suspension, cancelled on revocation and resumed on reactivation
+//! - Releaser-only execution with optional automatic settlement on the final signature
+//! - Failed settlements recorded with a structured reason, funds released and retry supported
+//! - Cancellation of authorized payments by the sender's signatory threshold
+//! - In-flight funds held in per-payment escrow accounts derived from the pallet id
+//! - Fungible holds with pallet-specific hold reasons instead of anonymous reserves
+//! - Per-sender unique payment references for idempotent initiation
+//! - Optional [`CheckGovernmentCaller`] signed extension keeping non-government callers out of
+//!   the transaction pool
+//! - Feeless signatory calls, rate limited per account
+//! - Optional [`PrioritizeUrgentPayments`] signed extension moving high-urgency emergency
+//!   payments ahead in the transaction pool
 
 use frame_support::{
     codec::{Decode, Encode},
-    dispatch::{DispatchError, DispatchResult},
-    traits::{Currency, Get, ReservableCurrency},
+    dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo, GetDispatchInfo, Pays, PostDispatchInfo},
+    traits::{
+        fungible::{Inspect, InspectHold, Mutate, MutateHold},
+        tokens::{Fortitude, Precision, Preservation, Restriction},
+        Contains, EnsureOrigin, Get,
+    },
     PalletId, RuntimeDebug,
 };
 use frame_system::ensure_signed;
 use scale_info::TypeInfo;
+use sp_core::H256;
 use sp_runtime::{
-    traits::{AccountIdConversion, Saturating, Zero},
-    Perbill,
+    traits::{AccountIdConversion, Dispatchable, Hash, Saturating, Zero},
+    Perbill, SaturatedConversion,
 };
-use sp_std::{vec::Vec, collections::btree_map::BTreeMap};
+use sp_std::{boxed::Box, vec::Vec};
 
+pub use extensions::{CheckGovernmentCaller, PrioritizeUrgentPayments};
 pub use pallet::*;
 
+pub mod extensions;
+pub mod migrations;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+const LOG_TARGET: &str = "runtime::inter-country-payments";
+
+// The dispatch code generated for calls returning `DispatchResultWithPostInfo` converts
+// results into their own type
+#[allow(clippy::useless_conversion)]
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
 
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
 
     #[pallet::pallet]
-    #[pallet::generate_store(pub(super) trait Store)]
     #[pallet::storage_version(STORAGE_VERSION)]
+    #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The overarching origin, which governance motions dispatch their calls with
+        type RuntimeOrigin: From<RawOrigin>;
         
         /// The currency used for government payments (VGV tokens)
-        type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+        type Currency: Inspect<Self::AccountId>
+            + Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// The overarching hold reason
+        type RuntimeHoldReason: From<HoldReason>;
 
         /// Maximum length for country codes (ISO 3166-1 alpha-3)
         #[pallet::constant]
@@ -53,6 +106,23 @@ pub mod pallet {
         #[pallet::constant]
         type MaxReferenceLength: Get<u32>;
 
+        /// Maximum length for international organisation codes
+        #[pallet::constant]
+        type MaxOrganizationCodeLength: Get<u32>;
+
+        /// Maximum number of amount-tiered approval thresholds per government
+        #[pallet::constant]
+        type MaxApprovalTiers: Get<u32>;
+
+        /// Minimum number of distinct signatories a government must have (5 under the default
+        /// 3-of-5 policy)
+        #[pallet::constant]
+        type MinSignatories: Get<u32>;
+
+        /// Minimum signature threshold a government may set (3 under the default 3-of-5 policy)
+        #[pallet::constant]
+        type MinSignatureThreshold: Get<u32>;
+
         /// Fixed fee for government transactions
         #[pallet::constant]
         type GovernmentTransactionFee: Get<u128>;
@@ -60,6 +130,107 @@ pub mod pallet {
         /// The pallet id for sovereign account derivation
         #[pallet::constant]
         type PalletId: Get<PalletId>;
+
+        /// Origin allowed to register governments and organisations.
+        ///
+        /// Production chains should use [`EnsureGovernmentSupermajority`] instead of root.
+        type RegistrationOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Origin allowed to change the status of governments and organisations.
+        ///
+        /// Production chains should use [`EnsureGovernmentSupermajority`] instead of root.
+        type StatusOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Calls that Active governments can put to a governance motion
+        type Proposal: Parameter
+            + Dispatchable<RuntimeOrigin = <Self as Config>::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + From<Call<Self>>
+            + GetDispatchInfo;
+
+        /// Number of blocks a governance motion stays open for voting
+        #[pallet::constant]
+        type MotionVotingPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Deposit held from a candidate while its membership application is open, returned on
+        /// admission and burnt on rejection
+        #[pallet::constant]
+        type MembershipDeposit: Get<u128>;
+
+        /// Number of blocks Active governments have to vote on a membership application
+        #[pallet::constant]
+        type MembershipVotingPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Share of Active governments whose approval admits a candidate
+        #[pallet::constant]
+        type MembershipApprovalThreshold: Get<Perbill>;
+
+        /// Length in blocks of the period behind daily outflow caps
+        #[pallet::constant]
+        type DailyLimitPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Length in blocks of the period behind monthly and corridor outflow caps
+        #[pallet::constant]
+        type MonthlyLimitPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Blocks between approval of an account rotation and its enactment, during which
+        /// `StatusOrigin` may veto it
+        #[pallet::constant]
+        type RotationDelay: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of payments a government or organisation may have in flight at once,
+        /// as sender or recipient, counting failed payments until they are retried or cancelled
+        #[pallet::constant]
+        type MaxUnsettledPayments: Get<u32>;
+
+        /// Account receiving the remaining balance of a deregistered government that names no
+        /// successor
+        #[pallet::constant]
+        type TreasuryAccount: Get<Self::AccountId>;
+
+        /// Calls [`CheckGovernmentCaller`] admits from any signer, such as validator session
+        /// keys or membership applications
+        type ExemptCalls: Contains<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// Successful payment and government action calls a signatory may make without fees per
+        /// `FeelessCallWindow`; further calls pay the normal transaction fee
+        #[pallet::constant]
+        type MaxFeelessCalls: Get<u32>;
+
+        /// Length in blocks of the window behind `MaxFeelessCalls`
+        #[pallet::constant]
+        type FeelessCallWindow: Get<BlockNumberFor<Self>>;
+
+        /// Priority [`PrioritizeUrgentPayments`] adds for `Critical` emergency payments; `High`
+        /// ones receive half of it
+        #[pallet::constant]
+        type UrgentPaymentPriority: Get<sp_runtime::transaction_validity::TransactionPriority>;
+    }
+
+    #[pallet::origin]
+    pub type Origin = RawOrigin;
+
+    /// Why the pallet holds funds
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Amount and fee of an unsettled payment, held on its escrow account
+        PaymentInFlight,
+        /// Deposit held until a governance decision, such as a membership application
+        EscrowHold,
+        /// Bond posted by a party disputing a payment
+        DisputeBond,
+    }
+
+    impl HoldReason {
+        /// Every hold reason of the pallet
+        pub const ALL: [HoldReason; 3] =
+            [HoldReason::PaymentInFlight, HoldReason::EscrowHold, HoldReason::DisputeBond];
+    }
+
+    /// Origin for calls approved by a governance motion
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum RawOrigin {
+        /// Approved by the first number of Active governments out of the second
+        Governments(u32, u32),
     }
 
     /// Government verification status
@@ -140,7 +311,7 @@ pub mod pallet {
 
     /// Government registration information
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-    pub struct GovernmentInfo {
+    pub struct GovernmentInfo<AccountId> {
         /// ISO 3166-1 alpha-3 country code
         pub country_code: Vec<u8>,
         /// Official government name
@@ -151,12 +322,79 @@ pub mod pallet {
         pub status: GovernmentStatus,
         /// Multi-signature threshold for transactions
         pub signature_threshold: u32,
-        /// Authorized signatories
-        pub authorized_signatories: Vec<T::AccountId>,
+        /// Authorized signatories and their roles
+        pub authorized_signatories: Vec<Signatory<AccountId>>,
+        /// Approval thresholds by ascending amount ceiling; larger payments use `signature_threshold`
+        pub approval_tiers: Vec<ApprovalTier>,
+        /// Generation of the government account, part of its derivation; increases with every
+        /// rotation and with every new registration after a deregistration
+        pub account_generation: u32,
+        /// ISO 17442 Legal Entity Identifier
+        pub lei: Option<[u8; 20]>,
+        /// ISO 9362 Business Identifier Code (8 or 11 characters)
+        pub bic: Option<Vec<u8>>,
+        /// Hash of the official website address
+        pub website_hash: Option<H256>,
         /// Registration timestamp
         pub registered_at: u64,
     }
 
+    impl<AccountId: PartialEq + Clone> GovernmentInfo<AccountId> {
+        /// Whether `account` is an authorized signatory
+        pub fn is_signatory(&self, account: &AccountId) -> bool {
+            self.authorized_signatories.iter().any(|s| &s.account == account)
+        }
+
+        /// Whether `account` is an authorized signatory holding `role`
+        pub fn has_role(&self, account: &AccountId, role: &SignatoryRole) -> bool {
+            self.authorized_signatories
+                .iter()
+                .any(|s| &s.account == account && s.roles.contains(role))
+        }
+
+        /// Accounts of the signatories holding `role`
+        pub fn signatories_with_role(&self, role: &SignatoryRole) -> Vec<AccountId> {
+            self.authorized_signatories
+                .iter()
+                .filter(|s| s.roles.contains(role))
+                .map(|s| s.account.clone())
+                .collect()
+        }
+    }
+
+    /// Duties a signatory may perform for its government
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum SignatoryRole {
+        /// Prepares and initiates payments, and cancels pending ones
+        Initiator,
+        /// Approves payments and government actions
+        Approver,
+        /// Releases authorized payments for settlement
+        Releaser,
+        /// Reviews payments; may co-sign where an approval tier requires it
+        Auditor,
+    }
+
+    /// Government official authorized to act for its government
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct Signatory<AccountId> {
+        /// Signatory account
+        pub account: AccountId,
+        /// Duties the signatory may perform
+        pub roles: Vec<SignatoryRole>,
+    }
+
+    /// Signatures required to authorize payments up to an amount ceiling
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct ApprovalTier {
+        /// Largest payment amount the tier applies to
+        pub amount_ceiling: u128,
+        /// Signatures needed to authorize payments in the tier, at least `MinSignatureThreshold`
+        pub required_approvals: u32,
+        /// Roles that must each be held by at least one signer
+        pub required_roles: Vec<SignatoryRole>,
+    }
+
     /// Types of government institutions
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
     pub enum InstitutionType {
@@ -168,6 +406,282 @@ pub mod pallet {
         CustomsAuthority,
     }
 
+    /// International organisation registration information
+    ///
+    /// The organisation account is keyless and derived from its code; its signatories move
+    /// funds out of it through transfers approved by `signature_threshold` approvers.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct OrganizationInfo<AccountId> {
+        /// Organisation code (uppercase ASCII letters and digits, e.g. "UN", "WHO", "IMF")
+        pub organization_code: Vec<u8>,
+        /// Official organisation name
+        pub organization_name: Vec<u8>,
+        /// Verification status
+        pub status: GovernmentStatus,
+        /// Approvals required for a transfer out of the organisation account
+        pub signature_threshold: u32,
+        /// Authorized signatories and their roles
+        pub authorized_signatories: Vec<Signatory<AccountId>>,
+        /// Registration timestamp
+        pub registered_at: u64,
+    }
+
+    impl<AccountId: PartialEq> OrganizationInfo<AccountId> {
+        /// Whether `account` is an authorized signatory holding `role`
+        pub fn has_role(&self, account: &AccountId, role: &SignatoryRole) -> bool {
+            self.authorized_signatories
+                .iter()
+                .any(|s| &s.account == account && s.roles.contains(role))
+        }
+    }
+
+    /// Transfer out of an organisation account awaiting signatory approvals
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct OrganizationTransfer<AccountId> {
+        /// Recipient of the funds
+        pub dest: AccountId,
+        /// Amount to transfer
+        pub amount: u128,
+        /// Signatories that approved the transfer
+        pub approvals: Vec<AccountId>,
+    }
+
+    /// Open governance motion
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct GovernanceMotion<AccountId, BlockNumber> {
+        /// Government that proposed the motion
+        pub proposer: AccountId,
+        /// Governments voting in favour
+        pub ayes: Vec<AccountId>,
+        /// Governments voting against
+        pub nays: Vec<AccountId>,
+        /// Block at which voting closes
+        pub end: BlockNumber,
+    }
+
+    /// Open membership application of a candidate government
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct MembershipApplication<AccountId, BlockNumber> {
+        /// Account that submitted the application and posted the deposit
+        pub depositor: AccountId,
+        /// Deposit reserved from the depositor
+        pub deposit: u128,
+        /// Governments voting to admit the candidate
+        pub ayes: Vec<AccountId>,
+        /// Governments voting to reject the candidate
+        pub nays: Vec<AccountId>,
+        /// Block at which the application was submitted
+        pub submitted_at: BlockNumber,
+        /// Block at which voting closes
+        pub end: BlockNumber,
+    }
+
+    /// Outcome of a concluded membership application
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct MembershipDecision<AccountId, BlockNumber> {
+        /// Whether the candidate was admitted
+        pub approved: bool,
+        /// Governments that voted to admit the candidate
+        pub ayes: Vec<AccountId>,
+        /// Governments that voted to reject the candidate
+        pub nays: Vec<AccountId>,
+        /// Block at which the application was decided
+        pub decided_at: BlockNumber,
+    }
+
+    /// Rule a government applies to a specific counterparty
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum CounterpartyRule {
+        /// Counterparty is explicitly allowed
+        Allow,
+        /// Counterparty is blocked
+        Deny,
+    }
+
+    /// How a government's counterparty rules are enforced
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+    pub enum CounterpartyMode {
+        /// Every counterparty is permitted unless denied
+        #[default]
+        DenyListed,
+        /// Only explicitly allowed counterparties are permitted
+        AllowListedOnly,
+    }
+
+    /// Administrative action taken for a government once its signatory threshold approves
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum GovernmentAction<AccountId> {
+        /// Set or clear the rule for a counterparty
+        SetCounterpartyRule {
+            counterparty: AccountId,
+            rule: Option<CounterpartyRule>,
+        },
+        /// Change how counterparty rules are enforced
+        SetCounterpartyMode {
+            mode: CounterpartyMode,
+        },
+        /// Replace the government's spending limits
+        SetSpendingLimits {
+            limits: SpendingLimits,
+        },
+        /// Set or clear the monthly cap on payments to a counterparty
+        SetCorridorCap {
+            counterparty: AccountId,
+            cap: Option<u128>,
+        },
+        /// Replace the authorized signatories and base signature threshold
+        UpdateSignatories {
+            signatories: Vec<Signatory<AccountId>>,
+            signature_threshold: u32,
+        },
+        /// Replace the amount-tiered approval thresholds
+        SetApprovalTiers {
+            approval_tiers: Vec<ApprovalTier>,
+        },
+        /// Move the government to a freshly derived account
+        RotateAccount,
+        /// Correct or extend the government's profile
+        UpdateProfile {
+            update: GovernmentProfileUpdate,
+        },
+        /// Cancel an outgoing payment that has not been executed, including authorized ones
+        CancelPayment {
+            payment_id: u64,
+            reason: Vec<u8>,
+        },
+    }
+
+    /// Record of a payment cancelled by the sender's signatories
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct PaymentCancellation<AccountId, BlockNumber> {
+        /// Reason given for the cancellation
+        pub reason: Vec<u8>,
+        /// Signatories that approved the cancellation
+        pub signers: Vec<AccountId>,
+        /// Block at which the payment was cancelled
+        pub cancelled_at: BlockNumber,
+    }
+
+    /// Changes to a government's profile
+    ///
+    /// `None` leaves a field unchanged; for optional fields `Some(None)` clears the value.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct GovernmentProfileUpdate {
+        /// New official government name
+        pub government_name: Option<Vec<u8>>,
+        /// Institution type, which cannot change as the government account is derived from it;
+        /// only the current type is accepted
+        pub institution_type: Option<InstitutionType>,
+        /// New Legal Entity Identifier
+        pub lei: Option<Option<[u8; 20]>>,
+        /// New Business Identifier Code
+        pub bic: Option<Option<Vec<u8>>>,
+        /// New official website hash
+        pub website_hash: Option<Option<H256>>,
+    }
+
+    /// Superseded version of a government's profile, kept for audit
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct GovernmentProfile<BlockNumber> {
+        /// Official government name
+        pub government_name: Vec<u8>,
+        /// Institution type
+        pub institution_type: InstitutionType,
+        /// Legal Entity Identifier
+        pub lei: Option<[u8; 20]>,
+        /// Business Identifier Code
+        pub bic: Option<Vec<u8>>,
+        /// Official website hash
+        pub website_hash: Option<H256>,
+        /// Block at which this version was replaced
+        pub replaced_at: BlockNumber,
+    }
+
+    /// Summary of a deregistered government, kept after its state is removed
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct DeregisteredGovernment<AccountId, BlockNumber> {
+        /// ISO 3166-1 alpha-3 country code
+        pub country_code: Vec<u8>,
+        /// Official government name
+        pub government_name: Vec<u8>,
+        /// Institution type
+        pub institution_type: InstitutionType,
+        /// Number of payments the government took part in
+        pub payment_count: u32,
+        /// Total amount of completed outgoing payments
+        pub total_sent: u128,
+        /// Total amount of completed incoming payments
+        pub total_received: u128,
+        /// Account that received the remaining balance
+        pub successor: AccountId,
+        /// Balance moved to the successor
+        pub returned_balance: u128,
+        /// Registration timestamp
+        pub registered_at: u64,
+        /// Block at which the government was deregistered
+        pub deregistered_at: BlockNumber,
+    }
+
+    /// Approved account rotation awaiting enactment
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct PendingRotation<AccountId, BlockNumber> {
+        /// Account the government moves to
+        pub new_account: AccountId,
+        /// Block from which the rotation can be enacted
+        pub enact_at: BlockNumber,
+    }
+
+    /// Outflow controls a government applies to its own payments
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+    pub struct SpendingLimits {
+        /// Maximum amount of a single payment
+        pub max_single_payment: Option<u128>,
+        /// Maximum outflow per daily period
+        pub daily_cap: Option<u128>,
+        /// Maximum outflow per monthly period
+        pub monthly_cap: Option<u128>,
+        /// Approvals required for payments exceeding a limit; such payments are rejected when unset
+        pub elevated_threshold: Option<u32>,
+    }
+
+    /// Outflow accounted within one limit period
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+    pub struct UsageWindow<BlockNumber> {
+        /// Index of the period the outflow belongs to
+        pub period: BlockNumber,
+        /// Outflow settled within the period
+        pub spent: u128,
+    }
+
+    /// Feeless calls made by a signatory within one `FeelessCallWindow`
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+    pub struct FeelessWindow<BlockNumber> {
+        /// Index of the window the calls belong to
+        pub window: BlockNumber,
+        /// Feeless calls made within the window
+        pub calls: u32,
+    }
+
+    /// Current spending limits and outflow of a government
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct SpendingUtilisation {
+        /// Configured limits
+        pub limits: SpendingLimits,
+        /// Outflow settled in the current daily period
+        pub daily_spent: u128,
+        /// Outflow settled in the current monthly period
+        pub monthly_spent: u128,
+    }
+
+    /// Government action awaiting signatory approvals
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct GovernmentProposal<AccountId> {
+        /// Action to take once approved
+        pub action: GovernmentAction<AccountId>,
+        /// Signatories that approved the action
+        pub approvals: Vec<AccountId>,
+    }
+
     /// Inter-country payment transaction
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
     pub struct InterCountryPayment<AccountId> {
@@ -177,16 +691,33 @@ pub mod pallet {
         pub from_government: AccountId,
         /// Receiving government account
         pub to_government: AccountId,
+        /// Signatory that initiated the payment
+        pub initiator: AccountId,
         /// Payment amount in VGV tokens
         pub amount: u128,
         /// Purpose of the payment
         pub purpose: PaymentPurpose,
         /// Reference number for tracking
         pub reference: Vec<u8>,
-        /// Required signatures
+        /// Signatories eligible to sign when the payment was initiated (approvers and holders of
+        /// required roles); signing and authorization go by the current signatories
         pub required_signatures: Vec<AccountId>,
+        /// Roles that must each be held by at least one signer
+        pub required_roles: Vec<SignatoryRole>,
         /// Collected signatures
         pub signatures: Vec<(AccountId, Vec<u8>)>, // (signer, signature)
+        /// Number of signatures needed to authorize the payment
+        pub approval_threshold: u32,
+        /// Payment exceeds the sender's spending limits and follows the elevated approval path
+        pub exceeds_limits: bool,
+        /// Country code and institution type the recipient was resolved from, for payments
+        /// addressed by country
+        pub recipient_country: Option<(Vec<u8>, InstitutionType)>,
+        /// Settle in the `sign_payment` call that authorizes the payment instead of waiting for
+        /// a releaser
+        pub auto_execute: bool,
+        /// Why the last settlement attempt failed, while the payment is `Failed`
+        pub failure_reason: Option<PaymentFailureReason>,
         /// Transaction timestamp
         pub timestamp: u64,
         /// Payment status
@@ -206,19 +737,89 @@ pub mod pallet {
         Failed,
         /// Payment was cancelled before execution
         Cancelled,
+        /// Payment is held while a party is not Active and resumes once both parties are
+        Frozen,
+    }
+
+    /// Why settlement of an authorized payment failed
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum PaymentFailureReason {
+        /// The sending government is no longer Active
+        SenderNotActive,
+        /// The recipient is no longer Active
+        RecipientNotActive,
+        /// A counterparty rule of either party now forbids the payment
+        CounterpartyNotPermitted,
+        /// The payment no longer fits the sender's spending limits
+        SpendingLimitExceeded,
+        /// The escrowed amount could not be moved to the recipient
+        TransferFailed,
+        /// The transaction fee could not be withdrawn
+        FeeWithdrawalFailed,
     }
 
     #[pallet::storage]
     #[pallet::getter(fn government_info)]
     /// Government registration information
     pub type GovernmentRegistry<T: Config> = 
-        StorageMap<_, Blake2_128Concat, T::AccountId, GovernmentInfo>;
+        StorageMap<_, Blake2_128Concat, T::AccountId, GovernmentInfo<T::AccountId>>;
 
     #[pallet::storage]
     #[pallet::getter(fn country_to_account)]
-    /// Mapping from country code to government account
-    pub type CountryToAccount<T: Config> = 
-        StorageMap<_, Blake2_128Concat, Vec<u8>, T::AccountId>;
+    /// Mapping from country code and institution type to government account
+    pub type CountryToAccount<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        Blake2_128Concat,
+        InstitutionType,
+        T::AccountId,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_account_generation)]
+    /// First unused account generation of each country and institution type whose government
+    /// was deregistered, so a new registration does not reuse the former account
+    pub type NextAccountGeneration<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        Blake2_128Concat,
+        InstitutionType,
+        u32,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn is_listed_country_code)]
+    /// ISO 3166-1 alpha-3 country codes accepted by the network
+    pub type ValidCountryCodes<T: Config> = 
+        StorageMap<_, Blake2_128Concat, Vec<u8>, (), OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn signatory_of)]
+    /// Governments and organisations each account is an authorized signatory of
+    pub type SignatoryGovernments<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn feeless_calls)]
+    /// Feeless calls made by each signatory in its current window
+    pub type FeelessCalls<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, FeelessWindow<BlockNumberFor<T>>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn is_allowed_caller)]
+    /// Accounts [`CheckGovernmentCaller`] admits without being a government or signatory, such
+    /// as system or validator operators
+    pub type AllowedCallers<T: Config> = 
+        StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn payment_info)]
@@ -231,6 +832,30 @@ pub mod pallet {
     /// Next available payment ID
     pub type NextPaymentId<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn payment_id_by_reference)]
+    /// Payment id of each non-empty reference used by a sending government
+    pub type PaymentReferences<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        Vec<u8>,
+        u64,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn frozen_payment)]
+    /// Status each frozen payment returns to when it is unfrozen
+    pub type FrozenPayments<T: Config> = 
+        StorageMap<_, Blake2_128Concat, u64, PaymentStatus>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn payment_cancellation)]
+    /// Payments cancelled by a signatory quorum, with their reason and cancelling signers
+    pub type PaymentCancellations<T: Config> = 
+        StorageMap<_, Blake2_128Concat, u64, PaymentCancellation<T::AccountId, BlockNumberFor<T>>>;
+
     #[pallet::storage]
     #[pallet::getter(fn total_payments_volume)]
     /// Total volume of payments processed
@@ -242,6 +867,274 @@ pub mod pallet {
     pub type GovernmentPaymentHistory<T: Config> = 
         StorageMap<_, Blake2_128Concat, T::AccountId, Vec<u64>>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn unsettled_payments)]
+    /// Payments each government or organisation sends or receives that have not completed or
+    /// been cancelled, so that status changes and rotations need not walk the full history
+    pub type UnsettledPayments<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<u64, T::MaxUnsettledPayments>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn payment_totals)]
+    /// Amounts each government or organisation sent and received in completed payments
+    pub type PaymentTotals<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (u128, u128), ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn organization_info)]
+    /// International organisation registration information
+    pub type OrganizationRegistry<T: Config> = 
+        StorageMap<_, Blake2_128Concat, T::AccountId, OrganizationInfo<T::AccountId>>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn organization_to_account)]
+    /// Mapping from organisation code to organisation account
+    pub type OrganizationToAccount<T: Config> = 
+        StorageMap<_, Blake2_128Concat, Vec<u8>, T::AccountId>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn organization_transfer)]
+    /// Transfers out of organisation accounts awaiting signatory approvals, by organisation and
+    /// transfer id
+    pub type OrganizationTransfers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        u32,
+        OrganizationTransfer<T::AccountId>,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_organization_transfer_id)]
+    /// Next available transfer id for each organisation
+    pub type NextOrganizationTransferId<T: Config> = 
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn contributions_received)]
+    /// Contributions received by each organisation, per member state country code and contribution type
+    pub type ContributionsReceived<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        (Vec<u8>, ContributionType),
+        u128,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn active_government_count)]
+    /// Number of governments currently in `Active` status
+    pub type ActiveGovernmentCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn motion_call)]
+    /// Calls put to a governance motion, by call hash
+    pub type MotionCalls<T: Config> = 
+        StorageMap<_, Identity, T::Hash, <T as Config>::Proposal>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn motion_votes)]
+    /// Votes on open governance motions, by call hash
+    pub type MotionVotes<T: Config> = 
+        StorageMap<_, Identity, T::Hash, GovernanceMotion<T::AccountId, BlockNumberFor<T>>>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn government_motion)]
+    /// Open governance motions each government proposed or voted on, by government and call hash
+    pub type GovernmentMotions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Identity,
+        T::Hash,
+        (),
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn government_proposal)]
+    /// Government actions awaiting signatory approvals, by government and proposal id
+    pub type GovernmentProposals<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        u32,
+        GovernmentProposal<T::AccountId>,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_government_proposal_id)]
+    /// Next available proposal id for each government
+    pub type NextGovernmentProposalId<T: Config> = 
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn counterparty_rule)]
+    /// Counterparty rules set by each government
+    pub type CounterpartyRules<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        CounterpartyRule,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn counterparty_mode)]
+    /// How each government's counterparty rules are enforced
+    pub type CounterpartyModes<T: Config> = 
+        StorageMap<_, Blake2_128Concat, T::AccountId, CounterpartyMode, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn spending_limits)]
+    /// Spending limits configured by each government
+    pub type GovernmentSpendingLimits<T: Config> = 
+        StorageMap<_, Blake2_128Concat, T::AccountId, SpendingLimits, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn corridor_cap)]
+    /// Monthly caps on payments from a government to a specific counterparty
+    pub type CorridorCaps<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        u128,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn daily_outflow)]
+    /// Outflow of each government in its current daily period
+    pub type DailyOutflow<T: Config> = 
+        StorageMap<_, Blake2_128Concat, T::AccountId, UsageWindow<BlockNumberFor<T>>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn monthly_outflow)]
+    /// Outflow of each government in its current monthly period
+    pub type MonthlyOutflow<T: Config> = 
+        StorageMap<_, Blake2_128Concat, T::AccountId, UsageWindow<BlockNumberFor<T>>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn corridor_outflow)]
+    /// Outflow from a government to a counterparty in the current monthly period
+    pub type CorridorOutflow<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        UsageWindow<BlockNumberFor<T>>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn counterparty_index)]
+    /// Governments holding a counterparty rule, corridor cap or corridor outflow for each
+    /// counterparty, by counterparty and government
+    pub type CounterpartyIndex<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pending_rotation)]
+    /// Approved account rotations awaiting enactment, by current government account
+    pub type PendingRotations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        PendingRotation<T::AccountId, BlockNumberFor<T>>,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn government_profile_history)]
+    /// Superseded profile versions of each government, oldest first
+    pub type GovernmentProfileHistory<T: Config> = 
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<GovernmentProfile<BlockNumberFor<T>>>>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn deregistered_government)]
+    /// History summaries of deregistered governments, by former account
+    pub type DeregisteredGovernments<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        DeregisteredGovernment<T::AccountId, BlockNumberFor<T>>,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn membership_application)]
+    /// Open membership applications, by candidate account
+    pub type MembershipApplications<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        MembershipApplication<T::AccountId, BlockNumberFor<T>>,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn membership_decisions)]
+    /// Decided membership applications with their recorded votes, by candidate account
+    pub type MembershipDecisions<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<MembershipDecision<T::AccountId, BlockNumberFor<T>>>,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn government_membership_vote)]
+    /// Open membership applications each government voted on, by government and candidate
+    pub type GovernmentMembershipVotes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+    >;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::check_escrow_invariant().map(|_| ()).map_err(Into::into)
+        }
+    }
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// ISO 3166-1 alpha-3 country codes accepted from genesis
+        pub valid_country_codes: Vec<Vec<u8>>,
+        /// Accounts allowed to transact without being a government or signatory
+        pub allowed_callers: Vec<T::AccountId>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for code in &self.valid_country_codes {
+                assert!(
+                    Pallet::<T>::is_iso_alpha3(code),
+                    "genesis country codes must be three uppercase ASCII letters"
+                );
+                ValidCountryCodes::<T>::insert(code, ());
+            }
+            for account in &self.allowed_callers {
+                AllowedCallers::<T>::insert(account, ());
+            }
+        }
+    }
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -252,12 +1145,13 @@ pub mod pallet {
             institution_type: InstitutionType,
         },
 
-        /// Payment was initiated [payment_id, from, to, amount]
+        /// Payment was initiated [payment_id, from, to, amount, initiator]
         PaymentInitiated {
             payment_id: u64,
             from_government: T::AccountId,
             to_government: T::AccountId,
             amount: u128,
+            initiator: T::AccountId,
         },
 
         /// Payment signature was added [payment_id, signer]
@@ -290,70 +1184,459 @@ pub mod pallet {
             account: T::AccountId,
             status: GovernmentStatus,
         },
-    }
 
-    #[pallet::error]
-    pub enum Error<T> {
-        /// Government already registered
-        GovernmentAlreadyRegistered,
-        /// Government not found or not registered
-        GovernmentNotFound,
-        /// Government is not active (suspended/revoked/pending)
-        GovernmentNotActive,
-        /// Payment not found
-        PaymentNotFound,
-        /// Payment already completed or cancelled
-        PaymentNotPending,
-        /// Insufficient balance for payment
-        InsufficientBalance,
-        /// Not authorized to sign for this government
-        NotAuthorizedSigner,
-        /// Signature already provided by this signer
-        SignatureAlreadyProvided,
-        /// Payment amount must be greater than zero
-        ZeroAmount,
-        /// Country code too long
-        CountryCodeTooLong,
-        /// Reference too long
-        ReferenceTooLong,
-        /// Cannot pay to the same government
-        SelfPayment,
+        /// International organisation was registered [account, organization_code]
+        OrganizationRegistered {
+            account: T::AccountId,
+            organization_code: Vec<u8>,
+        },
+
+        /// International organisation status was updated [account, new_status]
+        OrganizationStatusUpdated {
+            account: T::AccountId,
+            status: GovernmentStatus,
+        },
+
+        /// Contribution was credited to an organisation [organization, member_state, contribution_type, amount]
+        ContributionRecorded {
+            organization: T::AccountId,
+            member_state: Vec<u8>,
+            contribution_type: ContributionType,
+            amount: u128,
+        },
+
+        /// Organisation signatories were replaced [organization, signature_threshold]
+        OrganizationSignatoriesUpdated {
+            organization: T::AccountId,
+            signature_threshold: u32,
+        },
+
+        /// Transfer out of an organisation account was proposed [organization, transfer_id, proposer]
+        OrganizationTransferProposed {
+            organization: T::AccountId,
+            transfer_id: u32,
+            proposer: T::AccountId,
+        },
+
+        /// Transfer out of an organisation account was approved [organization, transfer_id, signer]
+        OrganizationTransferApproved {
+            organization: T::AccountId,
+            transfer_id: u32,
+            signer: T::AccountId,
+        },
+
+        /// Transfer reached its signatory threshold and was made [organization, transfer_id, dest, amount]
+        OrganizationTransferExecuted {
+            organization: T::AccountId,
+            transfer_id: u32,
+            dest: T::AccountId,
+            amount: u128,
+        },
+
+        /// Governance motion was proposed [motion_hash, proposer, end]
+        MotionProposed {
+            motion_hash: T::Hash,
+            proposer: T::AccountId,
+            end: BlockNumberFor<T>,
+        },
+
+        /// Vote was cast on a governance motion [motion_hash, voter, approve]
+        MotionVoted {
+            motion_hash: T::Hash,
+            voter: T::AccountId,
+            approve: bool,
+        },
+
+        /// Governance motion was closed [motion_hash, ayes, nays, active_governments]
+        MotionClosed {
+            motion_hash: T::Hash,
+            ayes: u32,
+            nays: u32,
+            active_governments: u32,
+        },
+
+        /// Approved governance motion was dispatched [motion_hash, result]
+        MotionExecuted {
+            motion_hash: T::Hash,
+            result: DispatchResult,
+        },
+
+        /// Candidate government applied for membership [candidate, country_code, deposit, end]
+        MembershipApplied {
+            candidate: T::AccountId,
+            country_code: Vec<u8>,
+            deposit: u128,
+            end: BlockNumberFor<T>,
+        },
+
+        /// Vote was cast on a membership application [candidate, voter, approve]
+        MembershipVoted {
+            candidate: T::AccountId,
+            voter: T::AccountId,
+            approve: bool,
+        },
+
+        /// Candidate government was admitted [candidate, ayes, nays]
+        MembershipApproved {
+            candidate: T::AccountId,
+            ayes: u32,
+            nays: u32,
+        },
+
+        /// Candidate government was rejected and its deposit burnt [candidate, ayes, nays]
+        MembershipRejected {
+            candidate: T::AccountId,
+            ayes: u32,
+            nays: u32,
+        },
+
+        /// Government action was proposed by a signatory [government, proposal_id, proposer]
+        GovernmentActionProposed {
+            government: T::AccountId,
+            proposal_id: u32,
+            proposer: T::AccountId,
+        },
+
+        /// Government action was approved by a signatory [government, proposal_id, signer]
+        GovernmentActionApproved {
+            government: T::AccountId,
+            proposal_id: u32,
+            signer: T::AccountId,
+        },
+
+        /// Government action reached its signatory threshold and was applied [government, proposal_id]
+        GovernmentActionExecuted {
+            government: T::AccountId,
+            proposal_id: u32,
+        },
+
+        /// Counterparty rule was set or cleared [government, counterparty, rule]
+        CounterpartyRuleSet {
+            government: T::AccountId,
+            counterparty: T::AccountId,
+            rule: Option<CounterpartyRule>,
+        },
+
+        /// Counterparty enforcement mode was changed [government, mode]
+        CounterpartyModeSet {
+            government: T::AccountId,
+            mode: CounterpartyMode,
+        },
+
+        /// Spending limits were replaced [government, limits]
+        SpendingLimitsSet {
+            government: T::AccountId,
+            limits: SpendingLimits,
+        },
+
+        /// Corridor cap was set or cleared [government, counterparty, cap]
+        CorridorCapSet {
+            government: T::AccountId,
+            counterparty: T::AccountId,
+            cap: Option<u128>,
+        },
+
+        /// Authorized signatories were replaced [government, signature_threshold]
+        SignatoriesUpdated {
+            government: T::AccountId,
+            signature_threshold: u32,
+        },
+
+        /// Approval tiers were replaced [government, approval_tiers]
+        ApprovalTiersSet {
+            government: T::AccountId,
+            approval_tiers: Vec<ApprovalTier>,
+        },
+
+        /// Account rotation was approved by the signatories [old_account, new_account, enact_at]
+        AccountRotationScheduled {
+            old_account: T::AccountId,
+            new_account: T::AccountId,
+            enact_at: BlockNumberFor<T>,
+        },
+
+        /// Account rotation was vetoed by governance [old_account, new_account]
+        AccountRotationVetoed {
+            old_account: T::AccountId,
+            new_account: T::AccountId,
+        },
+
+        /// Government moved to a new account with its state and balances
+        /// [old_account, new_account, country_code, institution_type, free, reserved]
+        GovernmentAccountRotated {
+            old_account: T::AccountId,
+            new_account: T::AccountId,
+            country_code: Vec<u8>,
+            institution_type: InstitutionType,
+            free_balance: u128,
+            reserved_balance: u128,
+        },
+
+        /// State left under a former government account was removed [account, removed, complete]
+        FormerAccountStateCleared {
+            account: T::AccountId,
+            removed: u32,
+            complete: bool,
+        },
+
+        /// Country code was added to the list of valid codes [country_code]
+        CountryCodeAdded {
+            country_code: Vec<u8>,
+        },
+
+        /// Country code was removed from the list of valid codes [country_code]
+        CountryCodeRemoved {
+            country_code: Vec<u8>,
+        },
+
+        /// Government profile was updated; the previous version was archived [government, version]
+        GovernmentInfoUpdated {
+            government: T::AccountId,
+            version: u32,
+        },
+
+        /// Payment was frozen because a party is no longer Active [payment_id]
+        PaymentFrozen {
+            payment_id: u64,
+        },
+
+        /// Payment settlement failed and its funds were released [payment_id, reason]
+        PaymentFailed {
+            payment_id: u64,
+            reason: PaymentFailureReason,
+        },
+
+        /// Failed payment was funded again for another settlement attempt [payment_id, releaser]
+        PaymentRetried {
+            payment_id: u64,
+            releaser: T::AccountId,
+        },
+
+        /// Frozen payment resumed after both parties became Active again [payment_id, status]
+        PaymentUnfrozen {
+            payment_id: u64,
+            status: PaymentStatus,
+        },
+
+        /// Account was added to the caller allowlist [account]
+        AllowedCallerAdded {
+            account: T::AccountId,
+        },
+
+        /// Account was removed from the caller allowlist [account]
+        AllowedCallerRemoved {
+            account: T::AccountId,
+        },
+
+        /// Government was deregistered and its remaining balance moved
+        /// [government, successor, returned_balance, cancelled_payments]
+        GovernmentDeregistered {
+            government: T::AccountId,
+            successor: T::AccountId,
+            returned_balance: u128,
+            cancelled_payments: u32,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Government already registered
+        GovernmentAlreadyRegistered,
+        /// Government not found or not registered
+        GovernmentNotFound,
+        /// Government is not active (suspended/revoked/pending)
+        GovernmentNotActive,
+        /// Payment not found
+        PaymentNotFound,
+        /// Payment already completed or cancelled
+        PaymentNotPending,
+        /// Insufficient balance for payment
+        InsufficientBalance,
+        /// Not authorized to sign for this government
+        NotAuthorizedSigner,
+        /// Signature already provided by this signer
+        SignatureAlreadyProvided,
+        /// Payment amount must be greater than zero
+        ZeroAmount,
+        /// Country code too long
+        CountryCodeTooLong,
+        /// Reference too long
+        ReferenceTooLong,
+        /// Cannot pay to the same government
+        SelfPayment,
         /// Signature threshold must be greater than zero
         InvalidSignatureThreshold,
         /// Payment already has all required signatures
         PaymentAlreadyAuthorized,
+        /// Organisation already registered
+        OrganizationAlreadyRegistered,
+        /// Organisation not found or not registered
+        OrganizationNotFound,
+        /// Organisation is not active (suspended/revoked/pending)
+        OrganizationNotActive,
+        /// Organisation code too long
+        OrganizationCodeTooLong,
+        /// Organisation code must be non-empty uppercase ASCII letters and digits
+        InvalidOrganizationCode,
+        /// Organisation code is already assigned to another account
+        OrganizationCodeInUse,
+        /// Contribution recipient is not the organisation named in the payment purpose
+        OrganizationMismatch,
+        /// Signature threshold exceeds the number of signatories able to approve
+        SignatureThresholdTooHigh,
+        /// An identical motion is already open
+        DuplicateMotion,
+        /// Motion not found
+        MotionNotFound,
+        /// Government has already cast this vote on the motion
+        DuplicateVote,
+        /// Motion voting period has not ended and not every Active government has voted
+        MotionVotingOpen,
+        /// Membership application not found
+        ApplicationNotFound,
+        /// Membership voting period has not ended
+        MembershipVotingOpen,
+        /// Government action proposal not found
+        ProposalNotFound,
+        /// Sender has blocked this counterparty
+        CounterpartyBlocked,
+        /// Recipient has blocked the sender
+        BlockedByCounterparty,
+        /// Payment exceeds the sender's maximum single payment
+        SinglePaymentLimitExceeded,
+        /// Payment exceeds the sender's daily outflow cap
+        DailyLimitExceeded,
+        /// Payment exceeds the sender's monthly outflow cap
+        MonthlyLimitExceeded,
+        /// Payment exceeds the sender's cap for this counterparty
+        CorridorLimitExceeded,
+        /// More approval tiers than `MaxApprovalTiers`
+        TooManyApprovalTiers,
+        /// Approval tier ceilings must be strictly ascending
+        ApprovalTiersNotAscending,
+        /// Tier approvals must be non-decreasing, at least `MinSignatureThreshold` and not above
+        /// the signature threshold
+        InvalidTierApprovals,
+        /// Signatory does not hold the role required for this action
+        MissingRole,
+        /// The initiator of a payment cannot approve it
+        InitiatorCannotApprove,
+        /// An approval tier requires a role that no signatory holds
+        RequiredRoleUnassigned,
+        /// An account rotation is already awaiting enactment
+        RotationAlreadyPending,
+        /// No account rotation is awaiting enactment
+        RotationNotFound,
+        /// Rotation delay has not elapsed yet
+        RotationDelayNotElapsed,
+        /// This institution type is already registered for the country
+        InstitutionAlreadyRegistered,
+        /// Country code must be exactly three uppercase ASCII letters
+        InvalidCountryCode,
+        /// Country code is not in the list of valid ISO 3166-1 alpha-3 codes
+        UnknownCountryCode,
+        /// No institution of this type is registered for the country
+        CountryNotRegistered,
+        /// The institution registered for the country is not active
+        CountryNotActive,
+        /// At least one signatory is required
+        NoSignatories,
+        /// The same account is listed as signatory more than once
+        DuplicateSignatory,
+        /// The controlled account cannot be its own signatory
+        GovernmentAccountAsSignatory,
+        /// Fewer distinct signatories than `MinSignatories`
+        TooFewSignatories,
+        /// Signature threshold is below `MinSignatureThreshold`
+        ThresholdBelowMinimum,
+        /// Every signatory must hold at least one role
+        SignatoryWithoutRoles,
+        /// Legal Entity Identifier is malformed or fails its check digits
+        InvalidLei,
+        /// Business Identifier Code is malformed
+        InvalidBic,
+        /// Only a revoked government can be deregistered
+        GovernmentNotRevoked,
+        /// The remaining balance cannot be moved to the deregistered account itself
+        InvalidSuccessor,
+        /// The payment recipient is not active
+        RecipientNotActive,
+        /// Only a failed payment can be retried
+        PaymentNotFailed,
+        /// Payment was not sent by this government
+        NotPaymentSender,
+        /// A cancellation must state a reason
+        EmptyCancellationReason,
+        /// The sending government already used this payment reference
+        DuplicateReference,
+        /// Account is not on the caller allowlist
+        NotAllowedCaller,
+        /// The weight bound given is below the weight of the motion's call
+        WrongProposalWeight,
+        /// Motion voting period has ended
+        MotionVotingClosed,
+        /// Membership voting period has ended
+        MembershipVotingClosed,
+        /// The government is a candidate whose membership application is still open
+        MembershipApplicationOpen,
+        /// The institution type of a registered government cannot change
+        InstitutionTypeImmutable,
+        /// A party of the payment already has `MaxUnsettledPayments` payments in flight
+        TooManyUnsettledPayments,
+        /// The government holds more counterparty entries than the rotation was given
+        TooManyCounterpartyEntries,
+        /// The account still belongs to a registered government or organisation
+        AccountStillRegistered,
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Register a government entity
-        #[pallet::weight(10_000)]
+        ///
+        /// The government account is derived from the country code and institution type, see
+        /// [`Pallet::government_account_id`]. It has no private key: its funds move only through
+        /// payments approved by the government's signatories.
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
         #[pallet::call_index(0)]
         pub fn register_government(
             origin: OriginFor<T>,
-            account: T::AccountId,
             country_code: Vec<u8>,
             government_name: Vec<u8>,
             institution_type: InstitutionType,
             signature_threshold: u32,
-            authorized_signatories: Vec<T::AccountId>,
+            authorized_signatories: Vec<Signatory<T::AccountId>>,
+            approval_tiers: Vec<ApprovalTier>,
         ) -> DispatchResult {
-            ensure_root(origin)?; // Only sudo can register governments initially
+            T::RegistrationOrigin::ensure_origin(origin)?;
 
             ensure!(
                 country_code.len() <= T::MaxCountryCodeLength::get() as usize,
                 Error::<T>::CountryCodeTooLong
             );
+            Self::ensure_valid_country_code(&country_code)?;
+
+            let generation = Self::next_account_generation(&country_code, &institution_type);
+            let account = Self::government_account_id(&country_code, &institution_type, generation);
+            Self::validate_government_signatories(&account, &authorized_signatories, signature_threshold)?;
+            Self::validate_approval_tiers(&approval_tiers, signature_threshold, &authorized_signatories)?;
 
             ensure!(
-                signature_threshold > 0,
-                Error::<T>::InvalidSignatureThreshold
+                !CountryToAccount::<T>::contains_key(&country_code, &institution_type),
+                Error::<T>::InstitutionAlreadyRegistered
+            );
+            // A candidate applying for the same institution was given the same account
+            ensure!(
+                !MembershipApplications::<T>::contains_key(&account),
+                Error::<T>::MembershipApplicationOpen
             );
-
             ensure!(
                 !GovernmentRegistry::<T>::contains_key(&account),
                 Error::<T>::GovernmentAlreadyRegistered
             );
+            ensure!(
+                !OrganizationRegistry::<T>::contains_key(&account),
+                Error::<T>::OrganizationAlreadyRegistered
+            );
 
             let gov_info = GovernmentInfo {
                 country_code: country_code.clone(),
@@ -362,11 +1645,18 @@ pub mod pallet {
                 status: GovernmentStatus::Active,
                 signature_threshold,
                 authorized_signatories,
+                approval_tiers,
+                account_generation: generation,
+                lei: None,
+                bic: None,
+                website_hash: None,
                 registered_at: Self::current_timestamp(),
             };
 
             GovernmentRegistry::<T>::insert(&account, &gov_info);
-            CountryToAccount::<T>::insert(&country_code, &account);
+            CountryToAccount::<T>::insert(&country_code, &institution_type, &account);
+            Self::index_signatories(&account, &[], &gov_info.authorized_signatories);
+            Self::note_status_change(None, Some(&gov_info.status));
 
             Self::deposit_event(Event::GovernmentRegistered {
                 account,
@@ -377,93 +1667,97 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Initiate an inter-country payment
-        #[pallet::weight(10_000)]
+        /// Initiate an inter-country payment on behalf of a government (initiators only)
+        ///
+        /// A non-empty `reference` can be used only once per sending government, so a client can
+        /// safely retry and look the payment up with [`Pallet::payment_by_reference`]. With
+        /// `auto_execute` the payment settles in the `sign_payment` call that authorizes it.
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
         #[pallet::call_index(1)]
         pub fn initiate_payment(
             origin: OriginFor<T>,
+            from_government: T::AccountId,
             to_government: T::AccountId,
             amount: u128,
             purpose: PaymentPurpose,
             reference: Vec<u8>,
-        ) -> DispatchResult {
+            auto_execute: bool,
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
-            ensure!(amount > 0, Error::<T>::ZeroAmount);
-            ensure!(who != to_government, Error::<T>::SelfPayment);
-            ensure!(
-                reference.len() <= T::MaxReferenceLength::get() as usize,
-                Error::<T>::ReferenceTooLong
-            );
-
-            // Verify both governments are registered and active
-            let from_gov = Self::government_info(&who).ok_or(Error::<T>::GovernmentNotFound)?;
-            let to_gov = Self::government_info(&to_government).ok_or(Error::<T>::GovernmentNotFound)?;
-
-            ensure!(from_gov.status == GovernmentStatus::Active, Error::<T>::GovernmentNotActive);
-            ensure!(to_gov.status == GovernmentStatus::Active, Error::<T>::GovernmentNotActive);
-
-            // Check balance including fees
-            let total_cost = amount.saturating_add(T::GovernmentTransactionFee::get());
-            let balance = T::Currency::free_balance(&who);
-            ensure!(
-                balance >= total_cost.saturated_into(),
-                Error::<T>::InsufficientBalance
-            );
-
-            // Reserve the payment amount + fees
-            T::Currency::reserve(&who, total_cost.saturated_into())?;
-
-            let payment_id = Self::next_payment_id();
-            let payment = InterCountryPayment {
-                payment_id,
-                from_government: who.clone(),
-                to_government: to_government.clone(),
+            Self::do_initiate_payment(
+                who.clone(),
+                from_government,
+                to_government,
                 amount,
                 purpose,
                 reference,
-                required_signatures: from_gov.authorized_signatories.clone(),
-                signatures: Vec::new(),
-                timestamp: Self::current_timestamp(),
-                status: PaymentStatus::Pending,
-            };
+                None,
+                auto_execute,
+            )?;
 
-            PaymentRegistry::<T>::insert(payment_id, &payment);
-            NextPaymentId::<T>::put(payment_id.saturating_add(1));
+            Ok(Self::signatory_fee(&who))
+        }
 
-            // Add to payment history
-            let mut history = Self::government_payment_history(&who).unwrap_or_default();
-            history.push(payment_id);
-            GovernmentPaymentHistory::<T>::insert(&who, history);
+        /// Initiate a payment to the institution registered for a country (initiators only)
+        ///
+        /// The recipient is resolved through `CountryToAccount` and the resolved country code and
+        /// institution type are recorded on the payment.
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(20)]
+        #[allow(clippy::too_many_arguments)]
+        pub fn initiate_payment_to_country(
+            origin: OriginFor<T>,
+            from_government: T::AccountId,
+            country_code: Vec<u8>,
+            institution_type: InstitutionType,
+            amount: u128,
+            purpose: PaymentPurpose,
+            reference: Vec<u8>,
+            auto_execute: bool,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
 
-            Self::deposit_event(Event::PaymentInitiated {
-                payment_id,
-                from_government: who,
+            let to_government = Self::country_to_account(&country_code, &institution_type)
+                .ok_or(Error::<T>::CountryNotRegistered)?;
+            let to_gov = Self::government_info(&to_government).ok_or(Error::<T>::CountryNotRegistered)?;
+            ensure!(to_gov.status == GovernmentStatus::Active, Error::<T>::CountryNotActive);
+
+            Self::do_initiate_payment(
+                who.clone(),
+                from_government,
                 to_government,
                 amount,
-            });
+                purpose,
+                reference,
+                Some((country_code, institution_type)),
+                auto_execute,
+            )?;
 
-            Ok(())
+            Ok(Self::signatory_fee(&who))
         }
 
         /// Add signature to a payment
-        #[pallet::weight(10_000)]
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
         #[pallet::call_index(2)]
         pub fn sign_payment(
             origin: OriginFor<T>,
             payment_id: u64,
             signature: Vec<u8>,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
             let mut payment = Self::payment_info(payment_id).ok_or(Error::<T>::PaymentNotFound)?;
             ensure!(payment.status == PaymentStatus::Pending, Error::<T>::PaymentNotPending);
 
-            // Verify signer is authorized
-            ensure!(
-                payment.required_signatures.contains(&who),
-                Error::<T>::NotAuthorizedSigner
-            );
+            // Separation of duties: the initiator cannot approve its own payment
+            ensure!(payment.initiator != who, Error::<T>::InitiatorCannotApprove);
+
+            // Verify signer is currently authorized
+            let gov_info = Self::government_info(&payment.from_government)
+                .ok_or(Error::<T>::GovernmentNotFound)?;
+            ensure!(gov_info.is_signatory(&who), Error::<T>::NotAuthorizedSigner);
+            ensure!(Self::may_sign(&gov_info, &payment, &who), Error::<T>::MissingRole);
 
             // Check if signature already provided
             ensure!(
@@ -474,11 +1768,8 @@ pub mod pallet {
             // Add signature
             payment.signatures.push((who.clone(), signature));
 
-            // Check if we have all required signatures
-            let gov_info = Self::government_info(&payment.from_government)
-                .ok_or(Error::<T>::GovernmentNotFound)?;
-
-            if payment.signatures.len() >= gov_info.signature_threshold as usize {
+            let authorized = Self::is_authorized(&gov_info, &payment);
+            if authorized {
                 payment.status = PaymentStatus::Authorized;
                 Self::deposit_event(Event::PaymentAuthorized { payment_id });
             }
@@ -487,103 +1778,117 @@ pub mod pallet {
 
             Self::deposit_event(Event::PaymentSignatureAdded {
                 payment_id,
-                signer: who,
+                signer: who.clone(),
             });
 
-            Ok(())
+            if authorized && payment.auto_execute {
+                Self::execute_or_fail(&mut payment)?;
+            }
+
+            Ok(Self::signatory_fee(&who))
         }
 
-        /// Execute an authorized payment
-        #[pallet::weight(10_000)]
+        /// Execute an authorized payment (releasers of the sending government only)
+        ///
+        /// If settlement fails the payment becomes `Failed` with its funds released; the call
+        /// itself still succeeds so that the outcome is recorded.
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
         #[pallet::call_index(3)]
-        pub fn execute_payment(origin: OriginFor<T>, payment_id: u64) -> DispatchResult {
-            let _who = ensure_signed(origin)?;
+        pub fn execute_payment(origin: OriginFor<T>, payment_id: u64) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
 
             let mut payment = Self::payment_info(payment_id).ok_or(Error::<T>::PaymentNotFound)?;
             ensure!(payment.status == PaymentStatus::Authorized, Error::<T>::PaymentNotPending);
+            Self::ensure_role(&payment.from_government, &who, SignatoryRole::Releaser)?;
 
-            // Transfer the payment amount
-            T::Currency::repatriate_reserved(
-                &payment.from_government,
-                &payment.to_government,
-                payment.amount.saturated_into(),
-                frame_support::traits::BalanceStatus::Free,
-            )?;
+            Self::execute_or_fail(&mut payment)?;
 
-            // Pay transaction fee (unreserve and burn)
-            let fee_amount = T::GovernmentTransactionFee::get().saturated_into();
-            T::Currency::unreserve(&payment.from_government, fee_amount);
-            T::Currency::withdraw(
-                &payment.from_government,
-                fee_amount,
-                frame_support::traits::WithdrawReasons::FEE,
-                frame_support::traits::ExistenceRequirement::AllowDeath,
-            )?;
+            Ok(Self::signatory_fee(&who))
+        }
 
-            payment.status = PaymentStatus::Completed;
-            PaymentRegistry::<T>::insert(payment_id, &payment);
+        /// Fund a failed payment again and retry its settlement (releasers only)
+        ///
+        /// The amount and fee are moved into escrow anew. Signatures of accounts that may no
+        /// longer sign are dropped; if the rest no longer authorize the payment it returns to
+        /// `Pending` to be signed again instead of settling.
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(23)]
+        pub fn retry_payment(origin: OriginFor<T>, payment_id: u64) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
 
-            // Update total volume
-            TotalPaymentsVolume::<T>::put(
-                Self::total_payments_volume().saturating_add(payment.amount)
-            );
+            let mut payment = Self::payment_info(payment_id).ok_or(Error::<T>::PaymentNotFound)?;
+            ensure!(payment.status == PaymentStatus::Failed, Error::<T>::PaymentNotFailed);
+            let from_gov = Self::ensure_role(&payment.from_government, &who, SignatoryRole::Releaser)?;
+            ensure!(from_gov.status == GovernmentStatus::Active, Error::<T>::GovernmentNotActive);
 
-            Self::deposit_event(Event::PaymentCompleted {
-                payment_id,
-                from_government: payment.from_government,
-                to_government: payment.to_government,
-                amount: payment.amount,
-            });
+            Self::fund_escrow(&payment.from_government, payment_id, payment.amount)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
 
-            Ok(())
+            let mut signatures = sp_std::mem::take(&mut payment.signatures);
+            signatures.retain(|(signer, _)| Self::may_sign(&from_gov, &payment, signer));
+            payment.signatures = signatures;
+            let authorized = Self::is_authorized(&from_gov, &payment);
+            payment.status = if authorized { PaymentStatus::Authorized } else { PaymentStatus::Pending };
+            payment.failure_reason = None;
+            PaymentRegistry::<T>::insert(payment_id, &payment);
+            Self::deposit_event(Event::PaymentRetried { payment_id, releaser: who.clone() });
+
+            if authorized {
+                Self::execute_or_fail(&mut payment)?;
+            }
+
+            Ok(Self::signatory_fee(&who))
         }
 
-        /// Cancel a pending payment
-        #[pallet::weight(10_000)]
+        /// Cancel a pending, frozen or failed payment (initiators only)
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
         #[pallet::call_index(4)]
         pub fn cancel_payment(
             origin: OriginFor<T>,
             payment_id: u64,
             reason: Vec<u8>,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
             let mut payment = Self::payment_info(payment_id).ok_or(Error::<T>::PaymentNotFound)?;
-            ensure!(payment.status == PaymentStatus::Pending, Error::<T>::PaymentNotPending);
-
-            // Only the initiating government can cancel
-            ensure!(payment.from_government == who, Error::<T>::NotAuthorizedSigner);
-
-            // Unreserve the funds
-            let total_reserved = payment.amount.saturating_add(T::GovernmentTransactionFee::get());
-            T::Currency::unreserve(&who, total_reserved.saturated_into());
+            ensure!(
+                matches!(payment.status, PaymentStatus::Pending | PaymentStatus::Frozen | PaymentStatus::Failed),
+                Error::<T>::PaymentNotPending
+            );
 
-            payment.status = PaymentStatus::Cancelled;
-            PaymentRegistry::<T>::insert(payment_id, &payment);
+            // Only an initiator of the sending government can cancel
+            Self::ensure_role(&payment.from_government, &who, SignatoryRole::Initiator)?;
 
-            Self::deposit_event(Event::PaymentCancelled {
-                payment_id,
-                reason,
-            });
+            Self::do_cancel_payment(&mut payment, reason)?;
 
-            Ok(())
+            Ok(Self::signatory_fee(&who))
         }
 
         /// Update government status (admin only)
-        #[pallet::weight(10_000)]
+        ///
+        /// In-flight payments sent or received by the government follow the new status, see
+        /// [`Pallet::apply_payment_policy`]. The status of a candidate is decided by the
+        /// membership vote and cannot be changed while its application is open.
+        #[pallet::weight(Pallet::<T>::payment_policy_weight())]
         #[pallet::call_index(5)]
         pub fn update_government_status(
             origin: OriginFor<T>,
             government: T::AccountId,
             new_status: GovernmentStatus,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            T::StatusOrigin::ensure_origin(origin)?;
 
             let mut gov_info = Self::government_info(&government)
                 .ok_or(Error::<T>::GovernmentNotFound)?;
+            ensure!(
+                !MembershipApplications::<T>::contains_key(&government),
+                Error::<T>::MembershipApplicationOpen
+            );
 
+            Self::note_status_change(Some(&gov_info.status), Some(&new_status));
             gov_info.status = new_status.clone();
             GovernmentRegistry::<T>::insert(&government, &gov_info);
+            Self::apply_payment_policy(&government, &new_status)?;
 
             Self::deposit_event(Event::GovernmentStatusUpdated {
                 account: government,
@@ -592,47 +1897,2312 @@ pub mod pallet {
 
             Ok(())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        /// Get current timestamp (simplified for demo)
-        fn current_timestamp() -> u64 {
-            // In a real implementation, this would get the actual block timestamp
-            0u64
-        }
+        /// Register an international organisation as a contribution recipient
+        ///
+        /// Contributions settle in the organisation's keyless account derived from its code,
+        /// which its signatories control like a government's.
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(6)]
+        pub fn register_organization(
+            origin: OriginFor<T>,
+            organization_code: Vec<u8>,
+            organization_name: Vec<u8>,
+            signature_threshold: u32,
+            authorized_signatories: Vec<Signatory<T::AccountId>>,
+        ) -> DispatchResult {
+            T::RegistrationOrigin::ensure_origin(origin)?;
 
-        /// Get payment statistics for a government
-        pub fn get_government_stats(account: &T::AccountId) -> Option<(u32, u128, u128)> {
+            ensure!(
+                organization_code.len() <= T::MaxOrganizationCodeLength::get() as usize,
+                Error::<T>::OrganizationCodeTooLong
+            );
+            ensure!(
+                Self::is_valid_organization_code(&organization_code),
+                Error::<T>::InvalidOrganizationCode
+            );
+
+            let account = Self::organization_account_id(&organization_code);
+            Self::validate_government_signatories(&account, &authorized_signatories, signature_threshold)?;
+
+            ensure!(
+                !OrganizationRegistry::<T>::contains_key(&account),
+                Error::<T>::OrganizationAlreadyRegistered
+            );
+            ensure!(
+                !GovernmentRegistry::<T>::contains_key(&account),
+                Error::<T>::GovernmentAlreadyRegistered
+            );
+            ensure!(
+                !OrganizationToAccount::<T>::contains_key(&organization_code),
+                Error::<T>::OrganizationCodeInUse
+            );
+
+            let org_info = OrganizationInfo {
+                organization_code: organization_code.clone(),
+                organization_name,
+                status: GovernmentStatus::Active,
+                signature_threshold,
+                authorized_signatories,
+                registered_at: Self::current_timestamp(),
+            };
+
+            Self::index_signatories(&account, &[], &org_info.authorized_signatories);
+            OrganizationRegistry::<T>::insert(&account, &org_info);
+            OrganizationToAccount::<T>::insert(&organization_code, &account);
+
+            Self::deposit_event(Event::OrganizationRegistered {
+                account,
+                organization_code,
+            });
+
+            Ok(())
+        }
+
+        /// Update international organisation status (admin only)
+        #[pallet::weight(Pallet::<T>::payment_policy_weight())]
+        #[pallet::call_index(7)]
+        pub fn update_organization_status(
+            origin: OriginFor<T>,
+            organization: T::AccountId,
+            new_status: GovernmentStatus,
+        ) -> DispatchResult {
+            T::StatusOrigin::ensure_origin(origin)?;
+
+            let mut org_info = Self::organization_info(&organization)
+                .ok_or(Error::<T>::OrganizationNotFound)?;
+
+            org_info.status = new_status.clone();
+            OrganizationRegistry::<T>::insert(&organization, &org_info);
+            Self::apply_payment_policy(&organization, &new_status)?;
+
+            Self::deposit_event(Event::OrganizationStatusUpdated {
+                account: organization,
+                status: new_status,
+            });
+
+            Ok(())
+        }
+
+        /// Replace the signatories of an organisation (admin only)
+        ///
+        /// Pending transfers keep only the approvals of signatories that remain approvers.
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(26)]
+        pub fn set_organization_signatories(
+            origin: OriginFor<T>,
+            organization: T::AccountId,
+            signature_threshold: u32,
+            authorized_signatories: Vec<Signatory<T::AccountId>>,
+        ) -> DispatchResult {
+            T::RegistrationOrigin::ensure_origin(origin)?;
+
+            let mut org_info = Self::organization_info(&organization)
+                .ok_or(Error::<T>::OrganizationNotFound)?;
+            Self::validate_government_signatories(&organization, &authorized_signatories, signature_threshold)?;
+
+            Self::index_signatories(&organization, &org_info.authorized_signatories, &authorized_signatories);
+            org_info.signature_threshold = signature_threshold;
+            org_info.authorized_signatories = authorized_signatories;
+            OrganizationRegistry::<T>::insert(&organization, &org_info);
+
+            Self::deposit_event(Event::OrganizationSignatoriesUpdated {
+                organization,
+                signature_threshold,
+            });
+
+            Ok(())
+        }
+
+        /// Propose a transfer out of an Active organisation's account (approvers only)
+        ///
+        /// The proposer's approval counts towards the organisation's signature threshold.
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(27)]
+        pub fn propose_organization_transfer(
+            origin: OriginFor<T>,
+            organization: T::AccountId,
+            dest: T::AccountId,
+            amount: u128,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let org_info = Self::ensure_organization_approver(&organization, &who)?;
+            ensure!(amount > 0, Error::<T>::ZeroAmount);
+
+            let transfer_id = Self::next_organization_transfer_id(&organization);
+            NextOrganizationTransferId::<T>::insert(&organization, transfer_id.saturating_add(1));
+
+            Self::deposit_event(Event::OrganizationTransferProposed {
+                organization: organization.clone(),
+                transfer_id,
+                proposer: who.clone(),
+            });
+
+            let transfer = OrganizationTransfer { dest, amount, approvals: sp_std::vec![who] };
+            Self::process_organization_transfer(organization, transfer_id, transfer, &org_info)
+        }
+
+        /// Approve a pending transfer out of an Active organisation's account (approvers only)
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(28)]
+        pub fn approve_organization_transfer(
+            origin: OriginFor<T>,
+            organization: T::AccountId,
+            transfer_id: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let org_info = Self::ensure_organization_approver(&organization, &who)?;
+
+            let mut transfer = Self::organization_transfer(&organization, transfer_id)
+                .ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(
+                !transfer.approvals.contains(&who),
+                Error::<T>::SignatureAlreadyProvided
+            );
+            transfer.approvals.push(who.clone());
+
+            Self::deposit_event(Event::OrganizationTransferApproved {
+                organization: organization.clone(),
+                transfer_id,
+                signer: who,
+            });
+
+            Self::process_organization_transfer(organization, transfer_id, transfer, &org_info)
+        }
+
+        /// Put a call to a vote of the Active governments (approvers, on behalf of their government)
+        ///
+        /// The proposing government's vote counts in favour.
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(8)]
+        pub fn propose_motion(
+            origin: OriginFor<T>,
+            government: T::AccountId,
+            call: Box<<T as Config>::Proposal>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_active_approver(&government, &who)?;
+
+            let motion_hash = T::Hashing::hash_of(&call);
+            ensure!(
+                !MotionCalls::<T>::contains_key(motion_hash),
+                Error::<T>::DuplicateMotion
+            );
+
+            let end = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::MotionVotingPeriod::get());
+            let motion = GovernanceMotion {
+                proposer: government.clone(),
+                ayes: sp_std::vec![government.clone()],
+                nays: Vec::new(),
+                end,
+            };
+
+            MotionCalls::<T>::insert(motion_hash, *call);
+            MotionVotes::<T>::insert(motion_hash, &motion);
+            GovernmentMotions::<T>::insert(&government, motion_hash, ());
+
+            Self::deposit_event(Event::MotionProposed {
+                motion_hash,
+                proposer: government,
+                end,
+            });
+
+            Ok(())
+        }
+
+        /// Vote on an open governance motion (approvers, on behalf of their government)
+        ///
+        /// A government may change its vote until the voting period ends.
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(9)]
+        pub fn vote_motion(
+            origin: OriginFor<T>,
+            government: T::AccountId,
+            motion_hash: T::Hash,
+            approve: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_active_approver(&government, &who)?;
+
+            let mut motion = Self::motion_votes(motion_hash).ok_or(Error::<T>::MotionNotFound)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < motion.end,
+                Error::<T>::MotionVotingClosed
+            );
+
+            let (cast, other) = if approve {
+                (&mut motion.ayes, &mut motion.nays)
+            } else {
+                (&mut motion.nays, &mut motion.ayes)
+            };
+            ensure!(!cast.contains(&government), Error::<T>::DuplicateVote);
+            other.retain(|voter| voter != &government);
+            cast.push(government.clone());
+
+            MotionVotes::<T>::insert(motion_hash, &motion);
+            GovernmentMotions::<T>::insert(&government, motion_hash, ());
+
+            Self::deposit_event(Event::MotionVoted {
+                motion_hash,
+                voter: government,
+                approve,
+            });
+
+            Ok(())
+        }
+
+        /// Close a governance motion, dispatching its call if approved
+        ///
+        /// Only votes of governments that are still Active count. The call is dispatched with
+        /// [`RawOrigin::Governments`] when ayes outnumber nays; whether that is enough is decided
+        /// by the origin check of the call itself. `proposal_weight_bound` must cover the weight
+        /// of the call and is charged up front; what the call did not use is refunded.
+        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(*proposal_weight_bound))]
+        #[pallet::call_index(10)]
+        pub fn close_motion(
+            origin: OriginFor<T>,
+            motion_hash: T::Hash,
+            proposal_weight_bound: Weight,
+        ) -> DispatchResultWithPostInfo {
+            let _who = ensure_signed(origin)?;
+
+            let motion = Self::motion_votes(motion_hash).ok_or(Error::<T>::MotionNotFound)?;
+            let ayes = Self::count_active(&motion.ayes);
+            let nays = Self::count_active(&motion.nays);
+            let active_governments = Self::active_government_count();
+
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= motion.end
+                    || ayes.saturating_add(nays) >= active_governments,
+                Error::<T>::MotionVotingOpen
+            );
+
+            let call = Self::motion_call(motion_hash).ok_or(Error::<T>::MotionNotFound)?;
+            let call_info = call.get_dispatch_info();
+            ensure!(
+                call_info.weight.all_lte(proposal_weight_bound),
+                Error::<T>::WrongProposalWeight
+            );
+
+            MotionCalls::<T>::remove(motion_hash);
+            MotionVotes::<T>::remove(motion_hash);
+            for government in sp_std::iter::once(&motion.proposer).chain(&motion.ayes).chain(&motion.nays) {
+                GovernmentMotions::<T>::remove(government, motion_hash);
+            }
+
+            Self::deposit_event(Event::MotionClosed {
+                motion_hash,
+                ayes,
+                nays,
+                active_governments,
+            });
+
+            let mut weight = Weight::from_parts(10_000, 0);
+            if ayes > nays {
+                let dispatched = call.dispatch(RawOrigin::Governments(ayes, active_governments).into());
+                weight = weight.saturating_add(frame_support::dispatch::extract_actual_weight(&dispatched, &call_info));
+                let result = dispatched.map(|_| ()).map_err(|e| e.error);
+                Self::deposit_event(Event::MotionExecuted { motion_hash, result });
+            }
+
+            Ok(Some(weight).into())
+        }
+
+        /// Apply for membership as a candidate government
+        ///
+        /// The candidate's derived government account is recorded as `Pending` and
+        /// `MembershipDeposit` is held from the caller until the Active governments decide
+        /// on the application. The deposit is returned if the candidate is admitted and burnt
+        /// if it is rejected.
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(11)]
+        pub fn apply_for_membership(
+            origin: OriginFor<T>,
+            country_code: Vec<u8>,
+            government_name: Vec<u8>,
+            institution_type: InstitutionType,
+            signature_threshold: u32,
+            authorized_signatories: Vec<Signatory<T::AccountId>>,
+            approval_tiers: Vec<ApprovalTier>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                country_code.len() <= T::MaxCountryCodeLength::get() as usize,
+                Error::<T>::CountryCodeTooLong
+            );
+            Self::ensure_valid_country_code(&country_code)?;
+
+            let generation = Self::next_account_generation(&country_code, &institution_type);
+            let candidate = Self::government_account_id(&country_code, &institution_type, generation);
+            Self::validate_government_signatories(&candidate, &authorized_signatories, signature_threshold)?;
+            Self::validate_approval_tiers(&approval_tiers, signature_threshold, &authorized_signatories)?;
+
+            ensure!(
+                !CountryToAccount::<T>::contains_key(&country_code, &institution_type),
+                Error::<T>::InstitutionAlreadyRegistered
+            );
+            ensure!(
+                !GovernmentRegistry::<T>::contains_key(&candidate),
+                Error::<T>::GovernmentAlreadyRegistered
+            );
+            ensure!(
+                !OrganizationRegistry::<T>::contains_key(&candidate),
+                Error::<T>::OrganizationAlreadyRegistered
+            );
+
+            let deposit = T::MembershipDeposit::get();
+            T::Currency::hold(&HoldReason::EscrowHold.into(), &who, deposit.saturated_into())?;
+
+            let gov_info = GovernmentInfo {
+                country_code: country_code.clone(),
+                government_name,
+                institution_type,
+                status: GovernmentStatus::Pending,
+                signature_threshold,
+                authorized_signatories,
+                approval_tiers,
+                account_generation: generation,
+                lei: None,
+                bic: None,
+                website_hash: None,
+                registered_at: Self::current_timestamp(),
+            };
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let end = now.saturating_add(T::MembershipVotingPeriod::get());
+            let application = MembershipApplication {
+                depositor: who,
+                deposit,
+                ayes: Vec::new(),
+                nays: Vec::new(),
+                submitted_at: now,
+                end,
+            };
+
+            GovernmentRegistry::<T>::insert(&candidate, &gov_info);
+            Self::index_signatories(&candidate, &[], &gov_info.authorized_signatories);
+            MembershipApplications::<T>::insert(&candidate, &application);
+
+            Self::deposit_event(Event::MembershipApplied {
+                candidate,
+                country_code,
+                deposit,
+                end,
+            });
+
+            Ok(())
+        }
+
+        /// Vote on a candidate government's membership application (approvers, on behalf of
+        /// their government)
+        ///
+        /// Votes are accepted until the voting period ends. The application is decided as soon
+        /// as the outcome can no longer change.
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(12)]
+        pub fn vote_on_membership(
+            origin: OriginFor<T>,
+            government: T::AccountId,
+            candidate: T::AccountId,
+            approve: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_active_approver(&government, &who)?;
+
+            let mut application = Self::membership_application(&candidate)
+                .ok_or(Error::<T>::ApplicationNotFound)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < application.end,
+                Error::<T>::MembershipVotingClosed
+            );
+
+            let (cast, other) = if approve {
+                (&mut application.ayes, &mut application.nays)
+            } else {
+                (&mut application.nays, &mut application.ayes)
+            };
+            ensure!(!cast.contains(&government), Error::<T>::DuplicateVote);
+            other.retain(|voter| voter != &government);
+            cast.push(government.clone());
+            GovernmentMembershipVotes::<T>::insert(&government, &candidate, ());
+
+            Self::deposit_event(Event::MembershipVoted {
+                candidate: candidate.clone(),
+                voter: government,
+                approve,
+            });
+
+            let ayes = Self::count_active(&application.ayes);
+            let nays = Self::count_active(&application.nays);
+            let active = Self::active_government_count();
+            let required = Self::membership_approvals_required(active);
+
+            if ayes >= required {
+                Self::conclude_membership(candidate, application, true)?;
+            } else if nays > active.saturating_sub(required) {
+                Self::conclude_membership(candidate, application, false)?;
+            } else {
+                MembershipApplications::<T>::insert(&candidate, &application);
+            }
+
+            Ok(())
+        }
+
+        /// Decide a membership application once its voting period has ended
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(13)]
+        pub fn close_membership_vote(origin: OriginFor<T>, candidate: T::AccountId) -> DispatchResult {
+            let _who = ensure_signed(origin)?;
+
+            let application = Self::membership_application(&candidate)
+                .ok_or(Error::<T>::ApplicationNotFound)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= application.end,
+                Error::<T>::MembershipVotingOpen
+            );
+
+            let required = Self::membership_approvals_required(Self::active_government_count());
+            let approved = Self::count_active(&application.ayes) >= required;
+            Self::conclude_membership(candidate, application, approved)
+        }
+
+        /// Propose an administrative action for a government (approvers only)
+        ///
+        /// The proposer's approval counts towards the government's signature threshold. Only
+        /// Active and Suspended governments can act, see [`Pallet::ensure_can_act`].
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(14)]
+        pub fn propose_government_action(
+            origin: OriginFor<T>,
+            government: T::AccountId,
+            action: GovernmentAction<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let gov_info = Self::ensure_role(&government, &who, SignatoryRole::Approver)?;
+            Self::ensure_can_act(&gov_info)?;
+
+            let proposal_id = Self::next_government_proposal_id(&government);
+            NextGovernmentProposalId::<T>::insert(&government, proposal_id.saturating_add(1));
+
+            Self::deposit_event(Event::GovernmentActionProposed {
+                government: government.clone(),
+                proposal_id,
+                proposer: who.clone(),
+            });
+
+            let proposal = GovernmentProposal {
+                action,
+                approvals: sp_std::vec![who.clone()],
+            };
+            Self::process_government_proposal(government, proposal_id, proposal, &gov_info)?;
+
+            Ok(Self::signatory_fee(&who))
+        }
+
+        /// Approve a pending administrative action for a government (approvers only)
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(15)]
+        pub fn approve_government_action(
+            origin: OriginFor<T>,
+            government: T::AccountId,
+            proposal_id: u32,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let gov_info = Self::ensure_role(&government, &who, SignatoryRole::Approver)?;
+            Self::ensure_can_act(&gov_info)?;
+
+            let mut proposal = Self::government_proposal(&government, proposal_id)
+                .ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(
+                !proposal.approvals.contains(&who),
+                Error::<T>::SignatureAlreadyProvided
+            );
+            proposal.approvals.push(who.clone());
+
+            Self::deposit_event(Event::GovernmentActionApproved {
+                government: government.clone(),
+                proposal_id,
+                signer: who.clone(),
+            });
+
+            Self::process_government_proposal(government, proposal_id, proposal, &gov_info)?;
+
+            Ok(Self::signatory_fee(&who))
+        }
+
+        /// Enact an approved account rotation once its delay has elapsed
+        ///
+        /// `counterparty_entries` must be at least [`Pallet::counterparty_entries`] of the
+        /// government; the weight is charged for that many entries and `MaxUnsettledPayments`.
+        #[pallet::weight(Pallet::<T>::account_state_weight(*counterparty_entries))]
+        #[pallet::call_index(16)]
+        pub fn enact_account_rotation(
+            origin: OriginFor<T>,
+            government: T::AccountId,
+            counterparty_entries: u32,
+        ) -> DispatchResult {
+            let _who = ensure_signed(origin)?;
+
+            let rotation = Self::pending_rotation(&government).ok_or(Error::<T>::RotationNotFound)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= rotation.enact_at,
+                Error::<T>::RotationDelayNotElapsed
+            );
+            Self::ensure_counterparty_entries(&government, counterparty_entries)?;
+
+            PendingRotations::<T>::remove(&government);
+            Self::rotate_account(&government, &rotation.new_account)
+        }
+
+        /// Veto an approved account rotation before it is enacted
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(17)]
+        pub fn veto_account_rotation(origin: OriginFor<T>, government: T::AccountId) -> DispatchResult {
+            T::StatusOrigin::ensure_origin(origin)?;
+
+            let rotation = PendingRotations::<T>::take(&government).ok_or(Error::<T>::RotationNotFound)?;
+
+            Self::deposit_event(Event::AccountRotationVetoed {
+                old_account: government,
+                new_account: rotation.new_account,
+            });
+
+            Ok(())
+        }
+
+        /// Remove up to `limit` proposals, payment references and vote records left under an
+        /// account that no longer belongs to a government or organisation, such as the former
+        /// account of a rotated government
+        #[pallet::weight(
+            Weight::from_parts(10_000, 0)
+                .saturating_add(T::DbWeight::get().reads(6))
+                .saturating_add(T::DbWeight::get().reads_writes(1, 1).saturating_mul((*limit).into()))
+        )]
+        #[pallet::call_index(29)]
+        pub fn clear_former_account_state(origin: OriginFor<T>, account: T::AccountId, limit: u32) -> DispatchResult {
+            let _who = ensure_signed(origin)?;
+
+            ensure!(
+                !GovernmentRegistry::<T>::contains_key(&account) && !OrganizationRegistry::<T>::contains_key(&account),
+                Error::<T>::AccountStillRegistered
+            );
+
+            let mut remaining = limit as usize;
+            remaining -= GovernmentProposals::<T>::drain_prefix(&account).take(remaining).count();
+            remaining -= PaymentReferences::<T>::drain_prefix(&account).take(remaining).count();
+            remaining -= GovernmentMotions::<T>::drain_prefix(&account).take(remaining).count();
+            remaining -= GovernmentMembershipVotes::<T>::drain_prefix(&account).take(remaining).count();
+
+            let complete = GovernmentProposals::<T>::iter_key_prefix(&account).next().is_none()
+                && PaymentReferences::<T>::iter_key_prefix(&account).next().is_none()
+                && GovernmentMotions::<T>::iter_key_prefix(&account).next().is_none()
+                && GovernmentMembershipVotes::<T>::iter_key_prefix(&account).next().is_none();
+            if complete {
+                NextGovernmentProposalId::<T>::remove(&account);
+            }
+
+            Self::deposit_event(Event::FormerAccountStateCleared {
+                account,
+                removed: limit.saturating_sub(remaining as u32),
+                complete,
+            });
+
+            Ok(())
+        }
+
+        /// Add ISO 3166-1 alpha-3 codes to the list of valid country codes
+        ///
+        /// Nothing is added if any of the codes is malformed.
+        #[pallet::weight(
+            Weight::from_parts(10_000, 0)
+                .saturating_add(T::DbWeight::get().writes(country_codes.len() as u64))
+        )]
+        #[pallet::call_index(18)]
+        pub fn add_country_code(origin: OriginFor<T>, country_codes: Vec<Vec<u8>>) -> DispatchResult {
+            T::RegistrationOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                country_codes.iter().all(|country_code| Self::is_iso_alpha3(country_code)),
+                Error::<T>::InvalidCountryCode
+            );
+            for country_code in country_codes {
+                ValidCountryCodes::<T>::insert(&country_code, ());
+                Self::deposit_event(Event::CountryCodeAdded { country_code });
+            }
+
+            Ok(())
+        }
+
+        /// Remove a code from the list of valid country codes
+        ///
+        /// Governments already registered under the code are unaffected.
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(19)]
+        pub fn remove_country_code(origin: OriginFor<T>, country_code: Vec<u8>) -> DispatchResult {
+            T::RegistrationOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                ValidCountryCodes::<T>::contains_key(&country_code),
+                Error::<T>::UnknownCountryCode
+            );
+            ValidCountryCodes::<T>::remove(&country_code);
+
+            Self::deposit_event(Event::CountryCodeRemoved { country_code });
+
+            Ok(())
+        }
+
+        /// Allow an account to transact without being a government or signatory
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(24)]
+        pub fn add_allowed_caller(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            T::RegistrationOrigin::ensure_origin(origin)?;
+
+            AllowedCallers::<T>::insert(&account, ());
+
+            Self::deposit_event(Event::AllowedCallerAdded { account });
+
+            Ok(())
+        }
+
+        /// Remove an account from the caller allowlist
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(25)]
+        pub fn remove_allowed_caller(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            T::RegistrationOrigin::ensure_origin(origin)?;
+
+            ensure!(AllowedCallers::<T>::contains_key(&account), Error::<T>::NotAllowedCaller);
+            AllowedCallers::<T>::remove(&account);
+
+            Self::deposit_event(Event::AllowedCallerRemoved { account });
+
+            Ok(())
+        }
+
+        /// Correct or extend a government's profile (governance)
+        ///
+        /// Governments can make the same change through their own signatories with
+        /// [`GovernmentAction::UpdateProfile`].
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::call_index(21)]
+        pub fn update_government_info(
+            origin: OriginFor<T>,
+            government: T::AccountId,
+            update: GovernmentProfileUpdate,
+        ) -> DispatchResult {
+            T::RegistrationOrigin::ensure_origin(origin)?;
+            Self::do_update_government_info(&government, update)
+        }
+
+        /// Remove a revoked government from the network (admin only)
+        ///
+        /// Payments the government sends or receives that are still in flight are cancelled and
+        /// their funds released to the sender, and an open membership application of the
+        /// government is withdrawn with its deposit returned. The remaining balance goes to
+        /// `successor`, or to `TreasuryAccount` if none is given. The government's registration
+        /// and counterparty state are removed and a summary of its history is kept in
+        /// `DeregisteredGovernments`; its proposals, references and votes are left for
+        /// [`Pallet::clear_former_account_state`].
+        ///
+        /// `counterparty_entries` must be at least [`Pallet::counterparty_entries`] of the
+        /// government; the weight is charged for that many entries and `MaxUnsettledPayments`.
+        #[pallet::weight(Pallet::<T>::account_state_weight(*counterparty_entries))]
+        #[pallet::call_index(22)]
+        pub fn deregister_government(
+            origin: OriginFor<T>,
+            government: T::AccountId,
+            successor: Option<T::AccountId>,
+            counterparty_entries: u32,
+        ) -> DispatchResult {
+            T::StatusOrigin::ensure_origin(origin)?;
+
+            let gov_info = Self::government_info(&government).ok_or(Error::<T>::GovernmentNotFound)?;
+            ensure!(gov_info.status == GovernmentStatus::Revoked, Error::<T>::GovernmentNotRevoked);
+            let successor = successor.unwrap_or_else(T::TreasuryAccount::get);
+            ensure!(successor != government, Error::<T>::InvalidSuccessor);
+            Self::ensure_counterparty_entries(&government, counterparty_entries)?;
+
+            // Settle payments in flight in either direction
+            let mut cancelled_payments = 0u32;
+            for payment_id in UnsettledPayments::<T>::take(&government) {
+                if let Some(mut payment) = Self::payment_info(payment_id) {
+                    Self::do_cancel_payment(&mut payment, b"government deregistered".to_vec())?;
+                    cancelled_payments = cancelled_payments.saturating_add(1);
+                }
+            }
+            let payment_count = Self::government_payment_history(&government).map_or(0, |history| history.len() as u32);
+            GovernmentPaymentHistory::<T>::remove(&government);
+            let (total_sent, total_received) = PaymentTotals::<T>::take(&government);
+
+            if let Some(application) = MembershipApplications::<T>::take(&government) {
+                T::Currency::release(
+                    &HoldReason::EscrowHold.into(),
+                    &application.depositor,
+                    application.deposit.saturated_into(),
+                    Precision::BestEffort,
+                )?;
+                for voter in application.ayes.iter().chain(&application.nays) {
+                    GovernmentMembershipVotes::<T>::remove(voter, &government);
+                }
+            }
+
+            // Return whatever the government still holds
+            for reason in HoldReason::ALL {
+                let held = T::Currency::balance_on_hold(&reason.into(), &government);
+                T::Currency::release(&reason.into(), &government, held, Precision::BestEffort)?;
+            }
+            let returned_balance = T::Currency::reducible_balance(&government, Preservation::Expendable, Fortitude::Polite);
+            T::Currency::transfer(&government, &successor, returned_balance, Preservation::Expendable)?;
+
+            Self::remove_government_state(&government, &gov_info);
+            NextAccountGeneration::<T>::insert(
+                &gov_info.country_code,
+                &gov_info.institution_type,
+                gov_info.account_generation.saturating_add(1),
+            );
+
+            DeregisteredGovernments::<T>::insert(
+                &government,
+                DeregisteredGovernment {
+                    country_code: gov_info.country_code,
+                    government_name: gov_info.government_name,
+                    institution_type: gov_info.institution_type,
+                    payment_count,
+                    total_sent,
+                    total_received,
+                    successor: successor.clone(),
+                    returned_balance: returned_balance.saturated_into(),
+                    registered_at: gov_info.registered_at,
+                    deregistered_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+
+            Self::deposit_event(Event::GovernmentDeregistered {
+                government,
+                successor,
+                returned_balance: returned_balance.saturated_into(),
+                cancelled_payments,
+            });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Get current timestamp (simplified for demo)
+        fn current_timestamp() -> u64 {
+            // In a real implementation, this would get the actual block timestamp
+            0u64
+        }
+
+        /// Get payment statistics for a government
+        pub fn get_government_stats(account: &T::AccountId) -> Option<(u32, u128, u128)> {
             let history = Self::government_payment_history(account)?;
             let payment_count = history.len() as u32;
-            
-            let (total_sent, total_received) = history.iter()
-                .filter_map(|&id| Self::payment_info(id))
-                .fold((0u128, 0u128), |(sent, received), payment| {
-                    if payment.status == PaymentStatus::Completed {
-                        if payment.from_government == *account {
-                            (sent.saturating_add(payment.amount), received)
-                        } else if payment.to_government == *account {
-                            (sent, received.saturating_add(payment.amount))
-                        } else {
-                            (sent, received)
+            let (total_sent, total_received) = Self::payment_totals(account);
+
+            Some((payment_count, total_sent, total_received))
+        }
+
+        /// Payment a government sent with the given reference
+        pub fn payment_by_reference(
+            from_government: &T::AccountId,
+            reference: &[u8],
+        ) -> Option<InterCountryPayment<T::AccountId>> {
+            Self::payment_id_by_reference(from_government, reference.to_vec()).and_then(Self::payment_info)
+        }
+
+        /// Registered institutions of a country and their government accounts
+        pub fn get_country_institutions(country_code: &[u8]) -> Vec<(InstitutionType, T::AccountId)> {
+            CountryToAccount::<T>::iter_prefix(country_code).collect()
+        }
+
+        /// Contribution statement of an organisation: (member state, contribution type, total received)
+        pub fn get_organization_contributions(
+            organization: &T::AccountId,
+        ) -> Vec<(Vec<u8>, ContributionType, u128)> {
+            ContributionsReceived::<T>::iter_prefix(organization)
+                .map(|((member_state, contribution_type), amount)| {
+                    (member_state, contribution_type, amount)
+                })
+                .collect()
+        }
+
+        /// Validate and record a payment initiated by signatory `who` for `from_government`
+        #[allow(clippy::too_many_arguments)]
+        fn do_initiate_payment(
+            who: T::AccountId,
+            from_government: T::AccountId,
+            to_government: T::AccountId,
+            amount: u128,
+            purpose: PaymentPurpose,
+            reference: Vec<u8>,
+            recipient_country: Option<(Vec<u8>, InstitutionType)>,
+            auto_execute: bool,
+        ) -> DispatchResult {
+            ensure!(amount > 0, Error::<T>::ZeroAmount);
+            ensure!(from_government != to_government, Error::<T>::SelfPayment);
+            ensure!(
+                reference.len() <= T::MaxReferenceLength::get() as usize,
+                Error::<T>::ReferenceTooLong
+            );
+            ensure!(
+                reference.is_empty() || !PaymentReferences::<T>::contains_key(&from_government, &reference),
+                Error::<T>::DuplicateReference
+            );
+
+            // Verify the sender is registered and active, and the recipient matches the purpose
+            let from_gov = Self::government_info(&from_government).ok_or(Error::<T>::GovernmentNotFound)?;
+            ensure!(from_gov.status == GovernmentStatus::Active, Error::<T>::GovernmentNotActive);
+            ensure!(from_gov.is_signatory(&who), Error::<T>::NotAuthorizedSigner);
+            ensure!(from_gov.has_role(&who, &SignatoryRole::Initiator), Error::<T>::MissingRole);
+            Self::ensure_valid_recipient(&to_government, &purpose)?;
+            if let PaymentPurpose::DevelopmentAid { beneficiary_country, .. } = &purpose {
+                Self::ensure_valid_country_code(beneficiary_country)?;
+            }
+            Self::ensure_counterparties_permit(&from_government, &to_government)?;
+
+            // Payments over a spending limit are rejected unless an elevated approval path is set
+            let limits = Self::spending_limits(&from_government);
+            let exceeds_limits = match Self::exceeded_spending_limit(&from_government, &to_government, amount) {
+                Some(error) => {
+                    ensure!(limits.elevated_threshold.is_some(), error);
+                    true
+                }
+                None => false,
+            };
+            let (tier_approvals, required_roles) = Self::required_approvals(&from_gov, amount);
+            let approval_threshold = match (exceeds_limits, limits.elevated_threshold) {
+                (true, Some(elevated)) => elevated.max(tier_approvals),
+                _ => tier_approvals,
+            };
+
+            // Approvers sign, together with holders of any role the tier requires
+            let required_signatures = from_gov
+                .authorized_signatories
+                .iter()
+                .filter(|s| {
+                    s.roles.contains(&SignatoryRole::Approver)
+                        || s.roles.iter().any(|role| required_roles.contains(role))
+                })
+                .map(|s| s.account.clone())
+                .collect();
+
+            // Check balance including fees
+            let total_cost = amount.saturating_add(T::GovernmentTransactionFee::get());
+            let balance = T::Currency::reducible_balance(&from_government, Preservation::Expendable, Fortitude::Polite);
+            ensure!(
+                balance >= total_cost.saturated_into(),
+                Error::<T>::InsufficientBalance
+            );
+
+            // Move the payment amount + fees into the payment's escrow
+            let payment_id = Self::next_payment_id();
+            Self::fund_escrow(&from_government, payment_id, amount)?;
+            let payment = InterCountryPayment {
+                payment_id,
+                from_government: from_government.clone(),
+                to_government: to_government.clone(),
+                initiator: who.clone(),
+                amount,
+                purpose,
+                reference,
+                required_signatures,
+                required_roles,
+                signatures: Vec::new(),
+                approval_threshold,
+                exceeds_limits,
+                recipient_country,
+                auto_execute,
+                failure_reason: None,
+                timestamp: Self::current_timestamp(),
+                status: PaymentStatus::Pending,
+            };
+
+            PaymentRegistry::<T>::insert(payment_id, &payment);
+            NextPaymentId::<T>::put(payment_id.saturating_add(1));
+            if !payment.reference.is_empty() {
+                PaymentReferences::<T>::insert(&from_government, &payment.reference, payment_id);
+            }
+
+            // Index the payment as in flight for both parties
+            for party in [&from_government, &to_government] {
+                UnsettledPayments::<T>::try_mutate(party, |payments| payments.try_push(payment_id))
+                    .map_err(|_| Error::<T>::TooManyUnsettledPayments)?;
+            }
+
+            // Add to payment history of both parties
+            let mut history = Self::government_payment_history(&from_government).unwrap_or_default();
+            history.push(payment_id);
+            GovernmentPaymentHistory::<T>::insert(&from_government, history);
+            GovernmentPaymentHistory::<T>::append(&to_government, payment_id);
+
+            Self::deposit_event(Event::PaymentInitiated {
+                payment_id,
+                from_government,
+                to_government,
+                amount,
+                initiator: who,
+            });
+
+            Ok(())
+        }
+
+        /// Settle an authorized payment, marking it `Failed` and refunding its escrow if
+        /// settlement is not possible
+        fn execute_or_fail(payment: &mut InterCountryPayment<T::AccountId>) -> DispatchResult {
+            if let Err(reason) = Self::do_execute_payment(payment) {
+                Self::refund_escrow(payment)?;
+
+                payment.status = PaymentStatus::Failed;
+                payment.failure_reason = Some(reason.clone());
+                PaymentRegistry::<T>::insert(payment.payment_id, &*payment);
+
+                Self::deposit_event(Event::PaymentFailed {
+                    payment_id: payment.payment_id,
+                    reason,
+                });
+            }
+            Ok(())
+        }
+
+        /// Settle an authorized payment, re-checking both parties, counterparty rules and limits
+        ///
+        /// Nothing is written unless settlement succeeds.
+        fn do_execute_payment(
+            payment: &mut InterCountryPayment<T::AccountId>,
+        ) -> Result<(), PaymentFailureReason> {
+            ensure!(
+                Self::is_active_party(&payment.from_government),
+                PaymentFailureReason::SenderNotActive
+            );
+            ensure!(
+                Self::is_active_party(&payment.to_government),
+                PaymentFailureReason::RecipientNotActive
+            );
+            Self::ensure_counterparties_permit(&payment.from_government, &payment.to_government)
+                .map_err(|_| PaymentFailureReason::CounterpartyNotPermitted)?;
+
+            // Limits may have been used up since initiation; elevated payments were approved as such
+            if !payment.exceeds_limits {
+                ensure!(
+                    Self::exceeded_spending_limit(&payment.from_government, &payment.to_government, payment.amount)
+                        .is_none(),
+                    PaymentFailureReason::SpendingLimitExceeded
+                );
+            }
+
+            // Pay the amount out of escrow, then burn the transaction fee held with it
+            let escrow = Self::payment_escrow_account(payment.payment_id);
+            let mut reason = PaymentFailureReason::TransferFailed;
+            frame_support::storage::with_storage_layer(|| -> DispatchResult {
+                T::Currency::transfer_on_hold(
+                    &HoldReason::PaymentInFlight.into(),
+                    &escrow,
+                    &payment.to_government,
+                    payment.amount.saturated_into(),
+                    Precision::Exact,
+                    Restriction::Free,
+                    Fortitude::Polite,
+                )?;
+
+                reason = PaymentFailureReason::FeeWithdrawalFailed;
+                T::Currency::burn_held(
+                    &HoldReason::PaymentInFlight.into(),
+                    &escrow,
+                    T::GovernmentTransactionFee::get().saturated_into(),
+                    Precision::Exact,
+                    Fortitude::Polite,
+                )?;
+                Ok(())
+            })
+            .map_err(|_| reason)?;
+            Self::close_escrow(payment.payment_id);
+
+            payment.status = PaymentStatus::Completed;
+            PaymentRegistry::<T>::insert(payment.payment_id, &*payment);
+            Self::unindex_payment(payment);
+            PaymentTotals::<T>::mutate(&payment.from_government, |(sent, _)| *sent = sent.saturating_add(payment.amount));
+            PaymentTotals::<T>::mutate(&payment.to_government, |(_, received)| {
+                *received = received.saturating_add(payment.amount)
+            });
+            Self::record_outflow(&payment.from_government, &payment.to_government, payment.amount);
+
+            // Update total volume
+            TotalPaymentsVolume::<T>::put(
+                Self::total_payments_volume().saturating_add(payment.amount)
+            );
+
+            // Credit the contribution statement of the receiving organisation
+            if let PaymentPurpose::InternationalContribution { contribution_type, .. } = &payment.purpose {
+                let member_state = Self::government_info(&payment.from_government)
+                    .map(|gov| gov.country_code)
+                    .unwrap_or_default();
+                ContributionsReceived::<T>::mutate(
+                    &payment.to_government,
+                    (member_state.clone(), contribution_type.clone()),
+                    |total| *total = total.saturating_add(payment.amount),
+                );
+                Self::deposit_event(Event::ContributionRecorded {
+                    organization: payment.to_government.clone(),
+                    member_state,
+                    contribution_type: contribution_type.clone(),
+                    amount: payment.amount,
+                });
+            }
+
+            Self::deposit_event(Event::PaymentCompleted {
+                payment_id: payment.payment_id,
+                from_government: payment.from_government.clone(),
+                to_government: payment.to_government.clone(),
+                amount: payment.amount,
+            });
+
+            Ok(())
+        }
+
+        /// Refund the escrowed amount and fee of an unexecuted payment and mark it cancelled
+        ///
+        /// Failed payments were refunded when they failed and are only marked cancelled.
+        fn do_cancel_payment(payment: &mut InterCountryPayment<T::AccountId>, reason: Vec<u8>) -> DispatchResult {
+            if payment.status != PaymentStatus::Failed {
+                Self::refund_escrow(payment)?;
+            }
+
+            payment.status = PaymentStatus::Cancelled;
+            PaymentRegistry::<T>::insert(payment.payment_id, &*payment);
+            FrozenPayments::<T>::remove(payment.payment_id);
+            Self::unindex_payment(payment);
+
+            Self::deposit_event(Event::PaymentCancelled {
+                payment_id: payment.payment_id,
+                reason,
+            });
+
+            Ok(())
+        }
+
+        /// Escrow account holding the amount and fee of a payment until it settles
+        pub fn payment_escrow_account(payment_id: u64) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating((b"esc", payment_id))
+        }
+
+        /// Move the amount and fee of a payment from its sender into its escrow, on hold
+        ///
+        /// The escrow holds its whole balance and has no free balance to keep it alive, so it is
+        /// given a provider reference until [`Self::close_escrow`].
+        fn fund_escrow(from: &T::AccountId, payment_id: u64, amount: u128) -> DispatchResult {
+            let escrow = Self::payment_escrow_account(payment_id);
+            let total_cost = amount.saturating_add(T::GovernmentTransactionFee::get());
+            frame_system::Pallet::<T>::inc_providers(&escrow);
+            T::Currency::transfer_and_hold(
+                &HoldReason::PaymentInFlight.into(),
+                from,
+                &escrow,
+                total_cost.saturated_into(),
+                Precision::Exact,
+                Preservation::Expendable,
+                Fortitude::Polite,
+            )?;
+            Ok(())
+        }
+
+        /// Drop the provider reference of an emptied escrow, letting the account be reaped
+        fn close_escrow(payment_id: u64) {
+            let _ = frame_system::Pallet::<T>::dec_providers(&Self::payment_escrow_account(payment_id));
+        }
+
+        /// Return everything held in a payment's escrow to the sender
+        fn refund_escrow(payment: &InterCountryPayment<T::AccountId>) -> DispatchResult {
+            let escrow = Self::payment_escrow_account(payment.payment_id);
+            let held = T::Currency::balance_on_hold(&HoldReason::PaymentInFlight.into(), &escrow);
+            if !held.is_zero() {
+                T::Currency::transfer_on_hold(
+                    &HoldReason::PaymentInFlight.into(),
+                    &escrow,
+                    &payment.from_government,
+                    held,
+                    Precision::BestEffort,
+                    Restriction::Free,
+                    Fortitude::Polite,
+                )?;
+            }
+            Self::close_escrow(payment.payment_id);
+            Ok(())
+        }
+
+        /// Remove a completed or cancelled payment from the `UnsettledPayments` of both parties
+        fn unindex_payment(payment: &InterCountryPayment<T::AccountId>) {
+            for party in [&payment.from_government, &payment.to_government] {
+                UnsettledPayments::<T>::mutate_exists(party, |payments| {
+                    if let Some(ids) = payments {
+                        ids.retain(|id| *id != payment.payment_id);
+                        if ids.is_empty() {
+                            *payments = None;
+                        }
+                    }
+                });
+            }
+        }
+
+        /// Whether a payment still has funds in escrow
+        fn is_unsettled(status: &PaymentStatus) -> bool {
+            matches!(status, PaymentStatus::Pending | PaymentStatus::Authorized | PaymentStatus::Frozen)
+        }
+
+        /// Check that every unsettled payment's escrow holds exactly its amount and fee, and
+        /// return the total held in escrow
+        pub fn check_escrow_invariant() -> Result<u128, &'static str> {
+            let fee = T::GovernmentTransactionFee::get();
+            let mut total = 0u128;
+            for payment in PaymentRegistry::<T>::iter_values().filter(|p| Self::is_unsettled(&p.status)) {
+                let escrow = Self::payment_escrow_account(payment.payment_id);
+                let held: u128 = T::Currency::balance_on_hold(&HoldReason::PaymentInFlight.into(), &escrow)
+                    .saturated_into();
+                ensure!(held == payment.amount.saturating_add(fee), "escrow does not match unsettled payment");
+                total = total.saturating_add(held);
+            }
+            Ok(total)
+        }
+
+        /// Bring the in-flight payments of `account` in line with its new status
+        ///
+        /// Revocation cancels them and refunds their escrow, suspension or a return to
+        /// pending freezes them, and reactivation resumes frozen payments whose other party is
+        /// Active as well. Only the payments in `UnsettledPayments` are visited; failed payments
+        /// among them hold no funds and are left to be retried or cancelled.
+        pub fn apply_payment_policy(account: &T::AccountId, status: &GovernmentStatus) -> DispatchResult {
+            for payment_id in Self::unsettled_payments(account) {
+                let mut payment = match Self::payment_info(payment_id) {
+                    Some(payment) => payment,
+                    None => continue,
+                };
+                if !Self::is_unsettled(&payment.status) {
+                    continue;
+                }
+
+                match status {
+                    GovernmentStatus::Revoked => {
+                        Self::do_cancel_payment(&mut payment, b"party revoked".to_vec())?;
+                    }
+                    GovernmentStatus::Suspended | GovernmentStatus::Pending => {
+                        if payment.status != PaymentStatus::Frozen {
+                            FrozenPayments::<T>::insert(payment_id, &payment.status);
+                            payment.status = PaymentStatus::Frozen;
+                            PaymentRegistry::<T>::insert(payment_id, &payment);
+                            Self::deposit_event(Event::PaymentFrozen { payment_id });
+                        }
+                    }
+                    GovernmentStatus::Active => {
+                        if payment.status == PaymentStatus::Frozen
+                            && Self::is_active_party(&payment.from_government)
+                            && Self::is_active_party(&payment.to_government)
+                        {
+                            let resumed = FrozenPayments::<T>::take(payment_id).unwrap_or(PaymentStatus::Pending);
+                            payment.status = resumed.clone();
+                            PaymentRegistry::<T>::insert(payment_id, &payment);
+                            Self::deposit_event(Event::PaymentUnfrozen {
+                                payment_id,
+                                status: resumed,
+                            });
+                        }
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        /// Weight of a status change applying [`Pallet::apply_payment_policy`] to up to
+        /// `MaxUnsettledPayments` payments
+        pub fn payment_policy_weight() -> Weight {
+            Weight::from_parts(10_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(4, 3))
+                .saturating_add(
+                    T::DbWeight::get().reads_writes(5, 5).saturating_mul(T::MaxUnsettledPayments::get().into()),
+                )
+        }
+
+        /// Replace the `old` signatories of a government or organisation with `new` in
+        /// `SignatoryGovernments`
+        fn index_signatories(
+            government: &T::AccountId,
+            old: &[Signatory<T::AccountId>],
+            new: &[Signatory<T::AccountId>],
+        ) {
+            for signatory in old {
+                SignatoryGovernments::<T>::remove(&signatory.account, government);
+            }
+            for signatory in new {
+                SignatoryGovernments::<T>::insert(&signatory.account, government, ());
+            }
+        }
+
+        /// Whether `who` may submit transactions under [`CheckGovernmentCaller`]
+        ///
+        /// Admits allowlisted accounts, and government accounts and signatories of governments
+        /// that are Active or Suspended, so that suspended governments can still wind down
+        /// their payments, as well as signatories of Active organisations.
+        pub fn is_permitted_caller(who: &T::AccountId) -> bool {
+            let admitted = |government: &T::AccountId| {
+                Self::government_info(government)
+                    .is_some_and(|gov| matches!(gov.status, GovernmentStatus::Active | GovernmentStatus::Suspended))
+            };
+            let organization = |organization: &T::AccountId| {
+                Self::organization_info(organization).is_some_and(|org| org.status == GovernmentStatus::Active)
+            };
+            AllowedCallers::<T>::contains_key(who)
+                || admitted(who)
+                || SignatoryGovernments::<T>::iter_key_prefix(who)
+                    .any(|party| admitted(&party) || organization(&party))
+        }
+
+        /// Whether a payment party (government or organisation) is registered and Active
+        fn is_active_party(account: &T::AccountId) -> bool {
+            let status = Self::government_info(account)
+                .map(|gov| gov.status)
+                .or_else(|| Self::organization_info(account).map(|org| org.status));
+            status == Some(GovernmentStatus::Active)
+        }
+
+        /// Remove a government's registration and the controls and counterparty state keyed by
+        /// its account
+        fn remove_government_state(government: &T::AccountId, gov_info: &GovernmentInfo<T::AccountId>) {
+            GovernmentRegistry::<T>::remove(government);
+            Self::index_signatories(government, &gov_info.authorized_signatories, &[]);
+            if Self::country_to_account(&gov_info.country_code, &gov_info.institution_type).as_ref()
+                == Some(government)
+            {
+                CountryToAccount::<T>::remove(&gov_info.country_code, &gov_info.institution_type);
+            }
+            Self::note_status_change(Some(&gov_info.status), None);
+
+            GovernmentProfileHistory::<T>::remove(government);
+            PendingRotations::<T>::remove(government);
+            CounterpartyModes::<T>::remove(government);
+            GovernmentSpendingLimits::<T>::remove(government);
+            DailyOutflow::<T>::remove(government);
+            MonthlyOutflow::<T>::remove(government);
+
+            // Rules, caps and usage the removed government holds
+            let counterparties: Vec<_> = CounterpartyRules::<T>::drain_prefix(government)
+                .map(|(counterparty, _)| counterparty)
+                .chain(CorridorCaps::<T>::drain_prefix(government).map(|(counterparty, _)| counterparty))
+                .chain(CorridorOutflow::<T>::drain_prefix(government).map(|(counterparty, _)| counterparty))
+                .collect();
+            for counterparty in counterparties {
+                CounterpartyIndex::<T>::remove(&counterparty, government);
+            }
+
+            // Rules, caps and usage other governments hold for the removed account
+            let owners: Vec<_> = CounterpartyIndex::<T>::drain_prefix(government).map(|(owner, _)| owner).collect();
+            for owner in owners {
+                CounterpartyRules::<T>::remove(&owner, government);
+                CorridorCaps::<T>::remove(&owner, government);
+                CorridorOutflow::<T>::remove(&owner, government);
+            }
+        }
+
+        /// Index or unindex `owner` under `counterparty` in `CounterpartyIndex`, depending on
+        /// whether it still holds a rule, cap or outflow for it
+        fn update_counterparty_index(owner: &T::AccountId, counterparty: &T::AccountId) {
+            if CounterpartyRules::<T>::contains_key(owner, counterparty)
+                || CorridorCaps::<T>::contains_key(owner, counterparty)
+                || CorridorOutflow::<T>::contains_key(owner, counterparty)
+            {
+                CounterpartyIndex::<T>::insert(counterparty, owner, ());
+            } else {
+                CounterpartyIndex::<T>::remove(counterparty, owner);
+            }
+        }
+
+        /// Apply a profile update, archiving the previous version
+        fn do_update_government_info(
+            government: &T::AccountId,
+            update: GovernmentProfileUpdate,
+        ) -> DispatchResult {
+            let mut gov_info = Self::government_info(government).ok_or(Error::<T>::GovernmentNotFound)?;
+
+            if let Some(Some(lei)) = &update.lei {
+                ensure!(Self::is_valid_lei(lei), Error::<T>::InvalidLei);
+            }
+            if let Some(Some(bic)) = &update.bic {
+                ensure!(Self::is_valid_bic(bic), Error::<T>::InvalidBic);
+            }
+
+            // The institution type derives the government account and its successors
+            ensure!(
+                update.institution_type.is_none()
+                    || update.institution_type.as_ref() == Some(&gov_info.institution_type),
+                Error::<T>::InstitutionTypeImmutable
+            );
+
+            let previous = GovernmentProfile {
+                government_name: gov_info.government_name.clone(),
+                institution_type: gov_info.institution_type.clone(),
+                lei: gov_info.lei,
+                bic: gov_info.bic.clone(),
+                website_hash: gov_info.website_hash,
+                replaced_at: frame_system::Pallet::<T>::block_number(),
+            };
+            let mut history = Self::government_profile_history(government).unwrap_or_default();
+            history.push(previous);
+            let version = history.len() as u32;
+            GovernmentProfileHistory::<T>::insert(government, history);
+
+            if let Some(government_name) = update.government_name {
+                gov_info.government_name = government_name;
+            }
+            if let Some(lei) = update.lei {
+                gov_info.lei = lei;
+            }
+            if let Some(bic) = update.bic {
+                gov_info.bic = bic;
+            }
+            if let Some(website_hash) = update.website_hash {
+                gov_info.website_hash = website_hash;
+            }
+            GovernmentRegistry::<T>::insert(government, &gov_info);
+
+            Self::deposit_event(Event::GovernmentInfoUpdated {
+                government: government.clone(),
+                version,
+            });
+
+            Ok(())
+        }
+
+        /// ISO 17442: 18 uppercase alphanumerics followed by two check digits (ISO 7064 MOD 97-10)
+        pub fn is_valid_lei(lei: &[u8; 20]) -> bool {
+            if !lei.iter().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+                || !lei[18..].iter().all(|c| c.is_ascii_digit())
+            {
+                return false;
+            }
+
+            // Letters count as two digits (A = 10 ... Z = 35)
+            let remainder = lei.iter().fold(0u32, |acc, c| {
+                if c.is_ascii_digit() {
+                    (acc * 10 + (c - b'0') as u32) % 97
+                } else {
+                    (acc * 100 + (c - b'A' + 10) as u32) % 97
+                }
+            });
+            remainder == 1
+        }
+
+        /// ISO 9362: 4-letter institution, 2-letter country, 2-character location and an
+        /// optional 3-character branch code
+        pub fn is_valid_bic(bic: &[u8]) -> bool {
+            let alphanumeric = |c: &u8| c.is_ascii_uppercase() || c.is_ascii_digit();
+            (bic.len() == 8 || bic.len() == 11)
+                && bic[..6].iter().all(|c| c.is_ascii_uppercase())
+                && bic[6..].iter().all(alphanumeric)
+        }
+
+        /// Keyless account of an international organisation
+        pub fn organization_account_id(organization_code: &[u8]) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating((b"org", organization_code))
+        }
+
+        /// Keyless sovereign account of the government institution of a country
+        ///
+        /// `generation` counts the accounts used before for the country and institution type,
+        /// through rotations and deregistrations, so no account is ever reused.
+        pub fn government_account_id(
+            country_code: &[u8],
+            institution_type: &InstitutionType,
+            generation: u32,
+        ) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating((b"gov", country_code, institution_type, generation))
+        }
+
+        /// Ensure a government may take administrative actions through its signatories
+        ///
+        /// Pending candidates have not been admitted by the vote yet and revoked governments
+        /// await deregistration. Suspended governments keep their controls so they can, for
+        /// example, replace compromised signatories.
+        pub fn ensure_can_act(gov_info: &GovernmentInfo<T::AccountId>) -> DispatchResult {
+            ensure!(
+                matches!(gov_info.status, GovernmentStatus::Active | GovernmentStatus::Suspended),
+                Error::<T>::GovernmentNotActive
+            );
+            Ok(())
+        }
+
+        /// Ensure `who` is an approver of an Active government, for which it then acts
+        fn ensure_active_approver(government: &T::AccountId, who: &T::AccountId) -> DispatchResult {
+            let gov_info = Self::ensure_role(government, who, SignatoryRole::Approver)?;
+            ensure!(gov_info.status == GovernmentStatus::Active, Error::<T>::GovernmentNotActive);
+            Ok(())
+        }
+
+        /// Apply a government action once enough signatories approved it, otherwise store it
+        ///
+        /// Only approvals of accounts that are still approvers count, so approvals given by a
+        /// signatory that has since been removed, such as a compromised key, are dropped.
+        fn process_government_proposal(
+            government: T::AccountId,
+            proposal_id: u32,
+            mut proposal: GovernmentProposal<T::AccountId>,
+            gov_info: &GovernmentInfo<T::AccountId>,
+        ) -> DispatchResult {
+            proposal
+                .approvals
+                .retain(|approver| gov_info.has_role(approver, &SignatoryRole::Approver));
+
+            if proposal.approvals.len() < gov_info.signature_threshold as usize {
+                GovernmentProposals::<T>::insert(&government, proposal_id, &proposal);
+                return Ok(());
+            }
+
+            GovernmentProposals::<T>::remove(&government, proposal_id);
+            Self::apply_government_action(&government, proposal.action, &proposal.approvals)?;
+
+            Self::deposit_event(Event::GovernmentActionExecuted {
+                government,
+                proposal_id,
+            });
+
+            Ok(())
+        }
+
+        /// Apply a government action approved by `approvals`
+        fn apply_government_action(
+            government: &T::AccountId,
+            action: GovernmentAction<T::AccountId>,
+            approvals: &[T::AccountId],
+        ) -> DispatchResult {
+            let gov_info = Self::government_info(government)
+                .ok_or(Error::<T>::GovernmentNotFound)?;
+
+            match action {
+                GovernmentAction::SetCounterpartyRule { counterparty, rule } => {
+                    match &rule {
+                        Some(rule) => CounterpartyRules::<T>::insert(government, &counterparty, rule),
+                        None => CounterpartyRules::<T>::remove(government, &counterparty),
+                    }
+                    Self::update_counterparty_index(government, &counterparty);
+                    Self::deposit_event(Event::CounterpartyRuleSet {
+                        government: government.clone(),
+                        counterparty,
+                        rule,
+                    });
+                }
+                GovernmentAction::SetCounterpartyMode { mode } => {
+                    CounterpartyModes::<T>::insert(government, &mode);
+                    Self::deposit_event(Event::CounterpartyModeSet {
+                        government: government.clone(),
+                        mode,
+                    });
+                }
+                GovernmentAction::SetSpendingLimits { limits } => {
+                    if let Some(elevated) = limits.elevated_threshold {
+                        ensure!(elevated > 0, Error::<T>::InvalidSignatureThreshold);
+                        ensure!(
+                            elevated as usize <= Self::approvals_available(&gov_info.authorized_signatories),
+                            Error::<T>::SignatureThresholdTooHigh
+                        );
+                    }
+                    GovernmentSpendingLimits::<T>::insert(government, &limits);
+                    Self::deposit_event(Event::SpendingLimitsSet {
+                        government: government.clone(),
+                        limits,
+                    });
+                }
+                GovernmentAction::SetCorridorCap { counterparty, cap } => {
+                    match cap {
+                        Some(cap) => CorridorCaps::<T>::insert(government, &counterparty, cap),
+                        None => CorridorCaps::<T>::remove(government, &counterparty),
+                    }
+                    Self::update_counterparty_index(government, &counterparty);
+                    Self::deposit_event(Event::CorridorCapSet {
+                        government: government.clone(),
+                        counterparty,
+                        cap,
+                    });
+                }
+                GovernmentAction::UpdateSignatories { signatories, signature_threshold } => {
+                    Self::validate_government_signatories(government, &signatories, signature_threshold)?;
+                    Self::validate_approval_tiers(&gov_info.approval_tiers, signature_threshold, &signatories)?;
+                    if let Some(elevated) = Self::spending_limits(government).elevated_threshold {
+                        ensure!(
+                            elevated as usize <= Self::approvals_available(&signatories),
+                            Error::<T>::SignatureThresholdTooHigh
+                        );
+                    }
+
+                    Self::index_signatories(government, &gov_info.authorized_signatories, &signatories);
+                    let mut gov_info = gov_info;
+                    gov_info.authorized_signatories = signatories;
+                    gov_info.signature_threshold = signature_threshold;
+                    GovernmentRegistry::<T>::insert(government, &gov_info);
+
+                    Self::deposit_event(Event::SignatoriesUpdated {
+                        government: government.clone(),
+                        signature_threshold,
+                    });
+                }
+                GovernmentAction::SetApprovalTiers { approval_tiers } => {
+                    Self::validate_approval_tiers(
+                        &approval_tiers,
+                        gov_info.signature_threshold,
+                        &gov_info.authorized_signatories,
+                    )?;
+
+                    let mut gov_info = gov_info;
+                    gov_info.approval_tiers = approval_tiers.clone();
+                    GovernmentRegistry::<T>::insert(government, &gov_info);
+
+                    Self::deposit_event(Event::ApprovalTiersSet {
+                        government: government.clone(),
+                        approval_tiers,
+                    });
+                }
+                GovernmentAction::UpdateProfile { update } => {
+                    Self::do_update_government_info(government, update)?;
+                }
+                GovernmentAction::CancelPayment { payment_id, reason } => {
+                    ensure!(!reason.is_empty(), Error::<T>::EmptyCancellationReason);
+                    ensure!(
+                        reason.len() <= T::MaxReferenceLength::get() as usize,
+                        Error::<T>::ReferenceTooLong
+                    );
+
+                    let mut payment = Self::payment_info(payment_id).ok_or(Error::<T>::PaymentNotFound)?;
+                    ensure!(&payment.from_government == government, Error::<T>::NotPaymentSender);
+                    ensure!(Self::is_unsettled(&payment.status), Error::<T>::PaymentNotPending);
+
+                    PaymentCancellations::<T>::insert(
+                        payment_id,
+                        PaymentCancellation {
+                            reason: reason.clone(),
+                            signers: approvals.to_vec(),
+                            cancelled_at: frame_system::Pallet::<T>::block_number(),
+                        },
+                    );
+                    Self::do_cancel_payment(&mut payment, reason)?;
+                }
+                GovernmentAction::RotateAccount => {
+                    ensure!(
+                        !PendingRotations::<T>::contains_key(government),
+                        Error::<T>::RotationAlreadyPending
+                    );
+
+                    let new_account = Self::government_account_id(
+                        &gov_info.country_code,
+                        &gov_info.institution_type,
+                        gov_info.account_generation.saturating_add(1),
+                    );
+                    ensure!(
+                        !GovernmentRegistry::<T>::contains_key(&new_account),
+                        Error::<T>::GovernmentAlreadyRegistered
+                    );
+
+                    let enact_at = frame_system::Pallet::<T>::block_number()
+                        .saturating_add(T::RotationDelay::get());
+                    Self::deposit_event(Event::AccountRotationScheduled {
+                        old_account: government.clone(),
+                        new_account: new_account.clone(),
+                        enact_at,
+                    });
+
+                    PendingRotations::<T>::insert(government, PendingRotation { new_account, enact_at });
+                }
+            }
+            Ok(())
+        }
+
+        /// Move a government to `new`, together with its free and reserved balance
+        ///
+        /// Only payments still in flight are re-keyed, with their references; settled payments
+        /// keep the old account as party and stay in the moved history. Proposals and votes
+        /// made under the old account lapse, and whatever is left under it can be removed with
+        /// [`Pallet::clear_former_account_state`].
+        fn rotate_account(old: &T::AccountId, new: &T::AccountId) -> DispatchResult {
+            let mut gov_info = Self::government_info(old).ok_or(Error::<T>::GovernmentNotFound)?;
+            Self::ensure_can_act(&gov_info)?;
+            GovernmentRegistry::<T>::remove(old);
+            gov_info.account_generation = gov_info.account_generation.saturating_add(1);
+            GovernmentRegistry::<T>::insert(new, &gov_info);
+            CountryToAccount::<T>::insert(&gov_info.country_code, &gov_info.institution_type, new);
+            Self::index_signatories(old, &gov_info.authorized_signatories, &[]);
+            Self::index_signatories(new, &[], &gov_info.authorized_signatories);
+
+            // Payments in flight in either direction
+            let unsettled = UnsettledPayments::<T>::take(old);
+            for payment_id in unsettled.iter() {
+                PaymentRegistry::<T>::mutate(payment_id, |payment| {
+                    if let Some(payment) = payment {
+                        if &payment.from_government == old {
+                            payment.from_government = new.clone();
+                            if let Some(id) = PaymentReferences::<T>::take(old, &payment.reference) {
+                                PaymentReferences::<T>::insert(new, &payment.reference, id);
+                            }
+                        }
+                        if &payment.to_government == old {
+                            payment.to_government = new.clone();
                         }
-                    } else {
-                        (sent, received)
                     }
                 });
+            }
+            UnsettledPayments::<T>::insert(new, unsettled);
+            if let Some(history) = GovernmentPaymentHistory::<T>::take(old) {
+                GovernmentPaymentHistory::<T>::insert(new, history);
+            }
+            PaymentTotals::<T>::insert(new, PaymentTotals::<T>::take(old));
 
-            Some((payment_count, total_sent, total_received))
+            // Controls owned by the government
+            CounterpartyModes::<T>::insert(new, CounterpartyModes::<T>::take(old));
+            GovernmentSpendingLimits::<T>::insert(new, GovernmentSpendingLimits::<T>::take(old));
+            DailyOutflow::<T>::insert(new, DailyOutflow::<T>::take(old));
+            MonthlyOutflow::<T>::insert(new, MonthlyOutflow::<T>::take(old));
+            for (counterparty, rule) in CounterpartyRules::<T>::drain_prefix(old) {
+                CounterpartyRules::<T>::insert(new, &counterparty, rule);
+                CounterpartyIndex::<T>::remove(&counterparty, old);
+                CounterpartyIndex::<T>::insert(&counterparty, new, ());
+            }
+            for (counterparty, cap) in CorridorCaps::<T>::drain_prefix(old) {
+                CorridorCaps::<T>::insert(new, &counterparty, cap);
+                CounterpartyIndex::<T>::remove(&counterparty, old);
+                CounterpartyIndex::<T>::insert(&counterparty, new, ());
+            }
+            for (counterparty, window) in CorridorOutflow::<T>::drain_prefix(old) {
+                CorridorOutflow::<T>::insert(new, &counterparty, window);
+                CounterpartyIndex::<T>::remove(&counterparty, old);
+                CounterpartyIndex::<T>::insert(&counterparty, new, ());
+            }
+            if let Some(profiles) = GovernmentProfileHistory::<T>::take(old) {
+                GovernmentProfileHistory::<T>::insert(new, profiles);
+            }
+            if let Some(decisions) = MembershipDecisions::<T>::take(old) {
+                MembershipDecisions::<T>::insert(new, decisions);
+            }
+
+            // Rules, caps and usage other governments hold for the old account
+            let owners: Vec<_> = CounterpartyIndex::<T>::drain_prefix(old).map(|(owner, _)| owner).collect();
+            for owner in owners {
+                if let Some(rule) = CounterpartyRules::<T>::take(&owner, old) {
+                    CounterpartyRules::<T>::insert(&owner, new, rule);
+                }
+                if let Some(cap) = CorridorCaps::<T>::take(&owner, old) {
+                    CorridorCaps::<T>::insert(&owner, new, cap);
+                }
+                if CorridorOutflow::<T>::contains_key(&owner, old) {
+                    CorridorOutflow::<T>::insert(&owner, new, CorridorOutflow::<T>::take(&owner, old));
+                }
+                CounterpartyIndex::<T>::insert(new, &owner, ());
+            }
+
+            // Balances, keeping held funds on hold under the same reason. The new account has
+            // no balance yet, so a provider reference lets it receive holds before its free
+            // balance arrives.
+            frame_system::Pallet::<T>::inc_providers(new);
+            let mut reserved = 0u128;
+            for reason in HoldReason::ALL {
+                let held = T::Currency::balance_on_hold(&reason.into(), old);
+                if held.is_zero() {
+                    continue;
+                }
+                T::Currency::transfer_on_hold(
+                    &reason.into(),
+                    old,
+                    new,
+                    held,
+                    Precision::Exact,
+                    Restriction::OnHold,
+                    Fortitude::Polite,
+                )?;
+                reserved = reserved.saturating_add(held.saturated_into());
+            }
+            let free = T::Currency::reducible_balance(old, Preservation::Expendable, Fortitude::Polite);
+            if !free.is_zero() {
+                T::Currency::transfer(old, new, free, Preservation::Expendable)?;
+            }
+            let _ = frame_system::Pallet::<T>::dec_providers(new);
+
+            Self::deposit_event(Event::GovernmentAccountRotated {
+                old_account: old.clone(),
+                new_account: new.clone(),
+                country_code: gov_info.country_code,
+                institution_type: gov_info.institution_type,
+                free_balance: free.saturated_into(),
+                reserved_balance: reserved,
+            });
+
+            Ok(())
+        }
+
+        /// Counterparty rules, caps and outflows a government holds or others hold for it
+        fn counterparty_keys(account: &T::AccountId) -> impl Iterator<Item = T::AccountId> {
+            CounterpartyRules::<T>::iter_key_prefix(account)
+                .chain(CorridorCaps::<T>::iter_key_prefix(account))
+                .chain(CorridorOutflow::<T>::iter_key_prefix(account))
+                .chain(CounterpartyIndex::<T>::iter_key_prefix(account))
+        }
+
+        /// Number of counterparty entries rotating or deregistering a government moves or removes
+        pub fn counterparty_entries(account: &T::AccountId) -> u32 {
+            Self::counterparty_keys(account).count() as u32
+        }
+
+        /// Ensure a government holds no more counterparty entries than `counterparty_entries`
+        fn ensure_counterparty_entries(account: &T::AccountId, counterparty_entries: u32) -> DispatchResult {
+            ensure!(
+                Self::counterparty_keys(account).nth(counterparty_entries as usize).is_none(),
+                Error::<T>::TooManyCounterpartyEntries
+            );
+            Ok(())
+        }
+
+        /// Weight of rotating or deregistering a government holding `counterparty_entries`
+        /// counterparty entries
+        pub fn account_state_weight(counterparty_entries: u32) -> Weight {
+            let per_item = T::DbWeight::get().reads_writes(2, 3);
+            Weight::from_parts(10_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(20, 20))
+                .saturating_add(per_item.saturating_mul(T::MaxUnsettledPayments::get().into()))
+                .saturating_add(per_item.saturating_mul(counterparty_entries.into()))
+        }
+
+        /// Signatures and signer roles required for a payment of `amount` under the government's
+        /// approval tiers
+        pub fn required_approvals(
+            gov_info: &GovernmentInfo<T::AccountId>,
+            amount: u128,
+        ) -> (u32, Vec<SignatoryRole>) {
+            gov_info
+                .approval_tiers
+                .iter()
+                .find(|tier| amount <= tier.amount_ceiling)
+                .map_or((gov_info.signature_threshold, Vec::new()), |tier| {
+                    (tier.required_approvals, tier.required_roles.clone())
+                })
+        }
+
+        /// Ensure `who` is an approver of an Active organisation, returning the organisation
+        fn ensure_organization_approver(
+            organization: &T::AccountId,
+            who: &T::AccountId,
+        ) -> Result<OrganizationInfo<T::AccountId>, DispatchError> {
+            let org_info = Self::organization_info(organization).ok_or(Error::<T>::OrganizationNotFound)?;
+            ensure!(
+                org_info.authorized_signatories.iter().any(|s| &s.account == who),
+                Error::<T>::NotAuthorizedSigner
+            );
+            ensure!(org_info.has_role(who, &SignatoryRole::Approver), Error::<T>::MissingRole);
+            ensure!(org_info.status == GovernmentStatus::Active, Error::<T>::OrganizationNotActive);
+            Ok(org_info)
+        }
+
+        /// Make an organisation transfer once enough signatories approved it, otherwise store it
+        ///
+        /// As for government actions, only approvals of accounts that are still approvers count.
+        fn process_organization_transfer(
+            organization: T::AccountId,
+            transfer_id: u32,
+            mut transfer: OrganizationTransfer<T::AccountId>,
+            org_info: &OrganizationInfo<T::AccountId>,
+        ) -> DispatchResult {
+            transfer
+                .approvals
+                .retain(|approver| org_info.has_role(approver, &SignatoryRole::Approver));
+
+            if transfer.approvals.len() < org_info.signature_threshold as usize {
+                OrganizationTransfers::<T>::insert(&organization, transfer_id, &transfer);
+                return Ok(());
+            }
+
+            OrganizationTransfers::<T>::remove(&organization, transfer_id);
+            T::Currency::transfer(
+                &organization,
+                &transfer.dest,
+                transfer.amount.saturated_into(),
+                Preservation::Expendable,
+            )?;
+
+            Self::deposit_event(Event::OrganizationTransferExecuted {
+                organization,
+                transfer_id,
+                dest: transfer.dest,
+                amount: transfer.amount,
+            });
+
+            Ok(())
+        }
+
+        /// Whether `who` may currently sign `payment`: an approver of the sending government or
+        /// a holder of a role the payment requires, other than its initiator
+        pub fn may_sign(
+            gov_info: &GovernmentInfo<T::AccountId>,
+            payment: &InterCountryPayment<T::AccountId>,
+            who: &T::AccountId,
+        ) -> bool {
+            who != &payment.initiator
+                && (gov_info.has_role(who, &SignatoryRole::Approver)
+                    || payment.required_roles.iter().any(|role| gov_info.has_role(who, role)))
+        }
+
+        /// Whether the signatures of accounts that may still sign reach the payment's approval
+        /// threshold and cover every role it requires
+        ///
+        /// Signatures of signatories removed or stripped of their roles since they signed, such
+        /// as compromised keys, do not count.
+        fn is_authorized(gov_info: &GovernmentInfo<T::AccountId>, payment: &InterCountryPayment<T::AccountId>) -> bool {
+            let signers: Vec<_> = payment
+                .signatures
+                .iter()
+                .map(|(signer, _)| signer)
+                .filter(|signer| Self::may_sign(gov_info, payment, signer))
+                .collect();
+            let roles_covered = payment
+                .required_roles
+                .iter()
+                .all(|role| signers.iter().any(|signer| gov_info.has_role(signer, role)));
+            signers.len() >= payment.approval_threshold as usize && roles_covered
         }
+
+        /// Ensure `who` is a signatory of `government` holding `role`, returning the government
+        fn ensure_role(
+            government: &T::AccountId,
+            who: &T::AccountId,
+            role: SignatoryRole,
+        ) -> Result<GovernmentInfo<T::AccountId>, DispatchError> {
+            let gov_info = Self::government_info(government).ok_or(Error::<T>::GovernmentNotFound)?;
+            ensure!(gov_info.is_signatory(who), Error::<T>::NotAuthorizedSigner);
+            ensure!(gov_info.has_role(who, &role), Error::<T>::MissingRole);
+            Ok(gov_info)
+        }
+
+        /// Ensure a signatory set can authorize payments for `account`
+        ///
+        /// The set must be non-empty, free of duplicates, must not contain `account` itself and
+        /// must have at least `MinSignatories` members. The threshold must be at least
+        /// `MinSignatureThreshold` and at most `approvers`, the signatures a payment can collect.
+        fn validate_signatories(
+            account: &T::AccountId,
+            signatories: &[T::AccountId],
+            approvers: usize,
+            threshold: u32,
+        ) -> DispatchResult {
+            ensure!(!signatories.is_empty(), Error::<T>::NoSignatories);
+
+            let mut distinct = signatories.to_vec();
+            distinct.sort();
+            distinct.dedup();
+            ensure!(distinct.len() == signatories.len(), Error::<T>::DuplicateSignatory);
+            ensure!(!signatories.contains(account), Error::<T>::GovernmentAccountAsSignatory);
+            ensure!(
+                distinct.len() >= T::MinSignatories::get() as usize,
+                Error::<T>::TooFewSignatories
+            );
+
+            ensure!(threshold > 0, Error::<T>::InvalidSignatureThreshold);
+            ensure!(
+                threshold >= T::MinSignatureThreshold::get(),
+                Error::<T>::ThresholdBelowMinimum
+            );
+            ensure!(threshold as usize <= approvers, Error::<T>::SignatureThresholdTooHigh);
+
+            Ok(())
+        }
+
+        /// Ensure a role-typed government signatory set is valid, see [`Self::validate_signatories`]
+        ///
+        /// The threshold is checked against [`Self::approvals_available`], so every payment can
+        /// be authorized whoever initiates it.
+        fn validate_government_signatories(
+            account: &T::AccountId,
+            signatories: &[Signatory<T::AccountId>],
+            threshold: u32,
+        ) -> DispatchResult {
+            ensure!(
+                signatories.iter().all(|s| !s.roles.is_empty()),
+                Error::<T>::SignatoryWithoutRoles
+            );
+
+            let accounts: Vec<_> = signatories.iter().map(|s| s.account.clone()).collect();
+            Self::validate_signatories(account, &accounts, Self::approvals_available(signatories), threshold)
+        }
+
+        /// Approvers left to sign a payment in the worst case
+        ///
+        /// The initiator of a payment cannot approve it, so one approver fewer is available when
+        /// a signatory holds both `Initiator` and `Approver`.
+        fn approvals_available(signatories: &[Signatory<T::AccountId>]) -> usize {
+            let approvers = signatories
+                .iter()
+                .filter(|s| s.roles.contains(&SignatoryRole::Approver))
+                .count();
+            let initiating_approver = signatories.iter().any(|s| {
+                s.roles.contains(&SignatoryRole::Initiator) && s.roles.contains(&SignatoryRole::Approver)
+            });
+            if initiating_approver {
+                approvers.saturating_sub(1)
+            } else {
+                approvers
+            }
+        }
+
+        /// Ensure an approval tier table is bounded, ordered and consistent with the threshold
+        ///
+        /// Ceilings must be strictly ascending, required approvals must be non-zero,
+        /// non-decreasing and at most `signature_threshold`, which covers larger payments, and
+        /// every required role must be held by at least one signatory.
+        fn validate_approval_tiers(
+            tiers: &[ApprovalTier],
+            signature_threshold: u32,
+            signatories: &[Signatory<T::AccountId>],
+        ) -> DispatchResult {
+            ensure!(
+                tiers.len() <= T::MaxApprovalTiers::get() as usize,
+                Error::<T>::TooManyApprovalTiers
+            );
+
+            for pair in tiers.windows(2) {
+                ensure!(
+                    pair[0].amount_ceiling < pair[1].amount_ceiling,
+                    Error::<T>::ApprovalTiersNotAscending
+                );
+                ensure!(
+                    pair[0].required_approvals <= pair[1].required_approvals,
+                    Error::<T>::InvalidTierApprovals
+                );
+            }
+
+            ensure!(
+                tiers.iter().all(|tier| {
+                    tier.required_approvals >= T::MinSignatureThreshold::get()
+                        && tier.required_approvals <= signature_threshold
+                }),
+                Error::<T>::InvalidTierApprovals
+            );
+
+            ensure!(
+                tiers.iter().flat_map(|tier| tier.required_roles.iter()).all(|role| {
+                    signatories.iter().any(|s| s.roles.contains(role))
+                }),
+                Error::<T>::RequiredRoleUnassigned
+            );
+
+            Ok(())
+        }
+
+        /// Index of the limit period the current block falls in
+        fn current_period(length: BlockNumberFor<T>) -> BlockNumberFor<T> {
+            if length.is_zero() {
+                Zero::zero()
+            } else {
+                frame_system::Pallet::<T>::block_number() / length
+            }
+        }
+
+        /// Post-dispatch info of a successful signatory call, waiving its fee unless `who` used
+        /// up its feeless calls for the current window
+        fn signatory_fee(who: &T::AccountId) -> PostDispatchInfo {
+            let window = Self::current_period(T::FeelessCallWindow::get());
+            let mut usage = Self::feeless_calls(who);
+            if usage.window != window {
+                usage = FeelessWindow { window, calls: 0 };
+            }
+
+            let pays_fee = if usage.calls >= T::MaxFeelessCalls::get() {
+                Pays::Yes
+            } else {
+                usage.calls = usage.calls.saturating_add(1);
+                FeelessCalls::<T>::insert(who, usage);
+                Pays::No
+            };
+
+            PostDispatchInfo { actual_weight: Some(Weight::from_parts(10_000, 0)), pays_fee }
+        }
+
+        /// Outflow of a usage window if it belongs to the current period
+        fn current_usage(window: &UsageWindow<BlockNumberFor<T>>, length: BlockNumberFor<T>) -> u128 {
+            if window.period == Self::current_period(length) {
+                window.spent
+            } else {
+                0
+            }
+        }
+
+        /// Add settled outflow to a usage window, resetting it when a new period has started
+        fn add_usage(window: &mut UsageWindow<BlockNumberFor<T>>, length: BlockNumberFor<T>, amount: u128) {
+            let period = Self::current_period(length);
+            if window.period != period {
+                *window = UsageWindow { period, spent: 0 };
+            }
+            window.spent = window.spent.saturating_add(amount);
+        }
+
+        /// First spending limit of the sender that a payment would exceed, if any
+        fn exceeded_spending_limit(from: &T::AccountId, to: &T::AccountId, amount: u128) -> Option<Error<T>> {
+            let limits = Self::spending_limits(from);
+
+            if limits.max_single_payment.is_some_and(|max| amount > max) {
+                return Some(Error::<T>::SinglePaymentLimitExceeded);
+            }
+
+            let daily = Self::current_usage(&Self::daily_outflow(from), T::DailyLimitPeriod::get());
+            if limits.daily_cap.is_some_and(|cap| daily.saturating_add(amount) > cap) {
+                return Some(Error::<T>::DailyLimitExceeded);
+            }
+
+            let monthly = Self::current_usage(&Self::monthly_outflow(from), T::MonthlyLimitPeriod::get());
+            if limits.monthly_cap.is_some_and(|cap| monthly.saturating_add(amount) > cap) {
+                return Some(Error::<T>::MonthlyLimitExceeded);
+            }
+
+            if let Some(cap) = Self::corridor_cap(from, to) {
+                let corridor = Self::current_usage(&Self::corridor_outflow(from, to), T::MonthlyLimitPeriod::get());
+                if corridor.saturating_add(amount) > cap {
+                    return Some(Error::<T>::CorridorLimitExceeded);
+                }
+            }
+
+            None
+        }
+
+        /// Account a settled payment against the sender's rolling windows
+        fn record_outflow(from: &T::AccountId, to: &T::AccountId, amount: u128) {
+            DailyOutflow::<T>::mutate(from, |window| {
+                Self::add_usage(window, T::DailyLimitPeriod::get(), amount)
+            });
+            MonthlyOutflow::<T>::mutate(from, |window| {
+                Self::add_usage(window, T::MonthlyLimitPeriod::get(), amount)
+            });
+            CorridorOutflow::<T>::mutate(from, to, |window| {
+                Self::add_usage(window, T::MonthlyLimitPeriod::get(), amount)
+            });
+            CounterpartyIndex::<T>::insert(to, from, ());
+        }
+
+        /// Spending limits and current outflow of a government
+        pub fn get_spending_utilisation(account: &T::AccountId) -> SpendingUtilisation {
+            SpendingUtilisation {
+                limits: Self::spending_limits(account),
+                daily_spent: Self::current_usage(&Self::daily_outflow(account), T::DailyLimitPeriod::get()),
+                monthly_spent: Self::current_usage(&Self::monthly_outflow(account), T::MonthlyLimitPeriod::get()),
+            }
+        }
+
+        /// Corridor cap and current monthly outflow from a government to a counterparty
+        pub fn get_corridor_utilisation(
+            account: &T::AccountId,
+            counterparty: &T::AccountId,
+        ) -> (Option<u128>, u128) {
+            (
+                Self::corridor_cap(account, counterparty),
+                Self::current_usage(&Self::corridor_outflow(account, counterparty), T::MonthlyLimitPeriod::get()),
+            )
+        }
+
+        /// Whether `government`'s counterparty rules permit dealing with `counterparty`
+        pub fn permits_counterparty(government: &T::AccountId, counterparty: &T::AccountId) -> bool {
+            match (Self::counterparty_mode(government), Self::counterparty_rule(government, counterparty)) {
+                (_, Some(CounterpartyRule::Deny)) => false,
+                (CounterpartyMode::AllowListedOnly, rule) => rule == Some(CounterpartyRule::Allow),
+                (CounterpartyMode::DenyListed, _) => true,
+            }
+        }
+
+        /// Ensure neither party of a payment has blocked the other
+        fn ensure_counterparties_permit(from: &T::AccountId, to: &T::AccountId) -> DispatchResult {
+            ensure!(Self::permits_counterparty(from, to), Error::<T>::CounterpartyBlocked);
+            ensure!(Self::permits_counterparty(to, from), Error::<T>::BlockedByCounterparty);
+            Ok(())
+        }
+
+        /// Number of the given voters that are currently Active governments
+        fn count_active(voters: &[T::AccountId]) -> u32 {
+            voters
+                .iter()
+                .filter(|voter| {
+                    Self::government_info(*voter)
+                        .is_some_and(|gov| gov.status == GovernmentStatus::Active)
+                })
+                .count() as u32
+        }
+
+        /// Approvals needed to admit a candidate, given the number of Active governments
+        fn membership_approvals_required(active: u32) -> u32 {
+            T::MembershipApprovalThreshold::get().mul_ceil(active).max(1)
+        }
+
+        /// Admit or reject a candidate, settle its deposit and record the decision
+        ///
+        /// The deposit is returned to the depositor on admission and burnt on rejection.
+        fn conclude_membership(
+            candidate: T::AccountId,
+            application: MembershipApplication<T::AccountId, BlockNumberFor<T>>,
+            approved: bool,
+        ) -> DispatchResult {
+            let mut gov_info = Self::government_info(&candidate)
+                .ok_or(Error::<T>::GovernmentNotFound)?;
+
+            if approved {
+                T::Currency::release(
+                    &HoldReason::EscrowHold.into(),
+                    &application.depositor,
+                    application.deposit.saturated_into(),
+                    Precision::BestEffort,
+                )?;
+            } else {
+                T::Currency::burn_held(
+                    &HoldReason::EscrowHold.into(),
+                    &application.depositor,
+                    application.deposit.saturated_into(),
+                    Precision::BestEffort,
+                    Fortitude::Polite,
+                )?;
+            }
+            MembershipApplications::<T>::remove(&candidate);
+            for voter in application.ayes.iter().chain(&application.nays) {
+                GovernmentMembershipVotes::<T>::remove(voter, &candidate);
+            }
+
+            let ayes = Self::count_active(&application.ayes);
+            let nays = Self::count_active(&application.nays);
+
+            if approved {
+                Self::note_status_change(Some(&gov_info.status), Some(&GovernmentStatus::Active));
+                gov_info.status = GovernmentStatus::Active;
+                GovernmentRegistry::<T>::insert(&candidate, &gov_info);
+                CountryToAccount::<T>::insert(&gov_info.country_code, &gov_info.institution_type, &candidate);
+                Self::deposit_event(Event::MembershipApproved {
+                    candidate: candidate.clone(),
+                    ayes,
+                    nays,
+                });
+            } else {
+                GovernmentRegistry::<T>::remove(&candidate);
+                Self::index_signatories(&candidate, &gov_info.authorized_signatories, &[]);
+                Self::deposit_event(Event::MembershipRejected {
+                    candidate: candidate.clone(),
+                    ayes,
+                    nays,
+                });
+            }
+
+            let decision = MembershipDecision {
+                approved,
+                ayes: application.ayes,
+                nays: application.nays,
+                decided_at: frame_system::Pallet::<T>::block_number(),
+            };
+            MembershipDecisions::<T>::append(&candidate, decision);
+
+            Ok(())
+        }
+
+        /// Keep `ActiveGovernmentCount` in sync with a government's status transition
+        fn note_status_change(old: Option<&GovernmentStatus>, new: Option<&GovernmentStatus>) {
+            let was_active = old == Some(&GovernmentStatus::Active);
+            let is_active = new == Some(&GovernmentStatus::Active);
+            if !was_active && is_active {
+                ActiveGovernmentCount::<T>::mutate(|count| *count = count.saturating_add(1));
+            } else if was_active && !is_active {
+                ActiveGovernmentCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+            }
+        }
+
+        /// Whether a code has the ISO 3166-1 alpha-3 shape: exactly three uppercase ASCII letters
+        pub fn is_iso_alpha3(code: &[u8]) -> bool {
+            code.len() == 3 && code.iter().all(|c| c.is_ascii_uppercase())
+        }
+
+        /// Trim surrounding whitespace and uppercase a country code
+        pub fn normalise_country_code(code: &[u8]) -> Vec<u8> {
+            let start = code.iter().position(|c| !c.is_ascii_whitespace()).unwrap_or(code.len());
+            let end = code.iter().rposition(|c| !c.is_ascii_whitespace()).map_or(start, |i| i + 1);
+            code[start..end].to_ascii_uppercase()
+        }
+
+        /// Ensure a country code is well-formed and in the list of valid codes
+        fn ensure_valid_country_code(code: &[u8]) -> DispatchResult {
+            ensure!(Self::is_iso_alpha3(code), Error::<T>::InvalidCountryCode);
+            ensure!(
+                ValidCountryCodes::<T>::contains_key(code),
+                Error::<T>::UnknownCountryCode
+            );
+            Ok(())
+        }
+
+        /// Organisation codes are non-empty uppercase ASCII letters and digits
+        fn is_valid_organization_code(code: &[u8]) -> bool {
+            !code.is_empty() && code.iter().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        }
+
+        /// Ensure the recipient is an active party of the kind required by the payment purpose.
+        ///
+        /// Contributions must go to the registered organisation named in the purpose; every
+        /// other purpose must target an active government.
+        fn ensure_valid_recipient(to: &T::AccountId, purpose: &PaymentPurpose) -> DispatchResult {
+            match purpose {
+                PaymentPurpose::InternationalContribution { organization, .. } => {
+                    let org = Self::organization_info(to).ok_or(Error::<T>::OrganizationNotFound)?;
+                    ensure!(&org.organization_code == organization, Error::<T>::OrganizationMismatch);
+                    ensure!(org.status == GovernmentStatus::Active, Error::<T>::OrganizationNotActive);
+                }
+                _ => {
+                    let gov = Self::government_info(to).ok_or(Error::<T>::GovernmentNotFound)?;
+                    ensure!(gov.status == GovernmentStatus::Active, Error::<T>::GovernmentNotActive);
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Ensures a call was approved by a governance motion backed by at least `N / D` of the
+/// Active governments.
+pub struct EnsureGovernmentProportionAtLeast<const N: u32, const D: u32>;
+
+impl<O, const N: u32, const D: u32> EnsureOrigin<O> for EnsureGovernmentProportionAtLeast<N, D>
+where
+    O: Into<Result<RawOrigin, O>> + From<RawOrigin>,
+{
+    type Success = ();
+
+    fn try_origin(o: O) -> Result<Self::Success, O> {
+        o.into().and_then(|o| match o {
+            RawOrigin::Governments(ayes, active)
+                if active > 0 && ayes.saturating_mul(D) >= N.saturating_mul(active) =>
+            {
+                Ok(())
+            }
+            r => Err(O::from(r)),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<O, ()> {
+        Ok(O::from(RawOrigin::Governments(1, 1)))
     }
 }
 
+/// Supermajority (two thirds) of the Active governments, the default for
+/// `RegistrationOrigin` and `StatusOrigin` on production chains.
+pub type EnsureGovernmentSupermajority = EnsureGovernmentProportionAtLeast<2, 3>;
+
 // Runtime API for government payment queries
 sp_api::decl_runtime_apis! {
-    pub trait InterCountryPaymentsApi<AccountId> {
-        fn get_government_info(account: AccountId) -> Option<GovernmentInfo>;
+    pub trait InterCountryPaymentsApi<AccountId> where AccountId: codec::Codec {
+        fn get_government_info(account: AccountId) -> Option<GovernmentInfo<AccountId>>;
+        fn get_government_account(country_code: Vec<u8>, institution_type: InstitutionType, generation: u32) -> AccountId;
+        fn get_institution_account(country_code: Vec<u8>, institution_type: InstitutionType) -> Option<AccountId>;
+        fn get_country_institutions(country_code: Vec<u8>) -> Vec<(InstitutionType, AccountId)>;
         fn get_payment_info(payment_id: u64) -> Option<InterCountryPayment<AccountId>>;
+        fn get_payment_by_reference(from_government: AccountId, reference: Vec<u8>) -> Option<InterCountryPayment<AccountId>>;
         fn get_government_stats(account: AccountId) -> Option<(u32, u128, u128)>;
         fn get_total_payments_volume() -> u128;
+        fn get_organization_info(account: AccountId) -> Option<OrganizationInfo<AccountId>>;
+        fn get_organization_contributions(account: AccountId) -> Vec<(Vec<u8>, ContributionType, u128)>;
+        fn get_spending_utilisation(account: AccountId) -> SpendingUtilisation;
+        fn get_corridor_utilisation(account: AccountId, counterparty: AccountId) -> (Option<u128>, u128);
+        fn get_payment_escrow_account(payment_id: u64) -> AccountId;
     }
 }
@@ -0,0 +1,825 @@
+//! Storage migrations for the inter-country payments pallet
+
+use super::*;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency, StorageVersion},
+};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Storage layouts of version 1, before amount-tiered approvals, role-typed signatories and
+/// keyless accounts were introduced.
+///
+/// No release was made between version 1 and version 2, so the layout changes of those features
+/// and of the ones following them are all applied by [`v2::NormaliseCountryCodes`].
+///
+/// Version 1 governments were registered under accounts with a private key, which stay in use
+/// after the upgrade. Whoever holds such a key can still move the government's free balance
+/// outside the pallet, so each of these governments must move to a keyless account with
+/// `GovernmentAction::RotateAccount`.
+pub mod v1 {
+    use super::*;
+
+    /// Roles given to version 1 signatories, which could initiate, sign and release payments
+    pub const SIGNATORY_ROLES: [SignatoryRole; 3] =
+        [SignatoryRole::Initiator, SignatoryRole::Approver, SignatoryRole::Releaser];
+
+    /// Version 1 government registration information
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct GovernmentInfo<AccountId> {
+        pub country_code: Vec<u8>,
+        pub government_name: Vec<u8>,
+        pub institution_type: InstitutionType,
+        pub status: GovernmentStatus,
+        pub signature_threshold: u32,
+        pub authorized_signatories: Vec<AccountId>,
+        pub registered_at: u64,
+    }
+
+    impl<AccountId> GovernmentInfo<AccountId> {
+        /// Current layout, with every signatory holding [`SIGNATORY_ROLES`], no approval tiers
+        /// and no profile identifiers
+        pub fn upgrade(self) -> crate::GovernmentInfo<AccountId> {
+            crate::GovernmentInfo {
+                country_code: self.country_code,
+                government_name: self.government_name,
+                institution_type: self.institution_type,
+                status: self.status,
+                signature_threshold: self.signature_threshold,
+                authorized_signatories: self
+                    .authorized_signatories
+                    .into_iter()
+                    .map(|account| Signatory { account, roles: SIGNATORY_ROLES.to_vec() })
+                    .collect(),
+                approval_tiers: Vec::new(),
+                account_generation: 0,
+                lei: None,
+                bic: None,
+                website_hash: None,
+                registered_at: self.registered_at,
+            }
+        }
+    }
+
+    /// Version 1 inter-country payment
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct InterCountryPayment<AccountId> {
+        pub payment_id: u64,
+        pub from_government: AccountId,
+        pub to_government: AccountId,
+        pub amount: u128,
+        pub purpose: PaymentPurpose,
+        pub reference: Vec<u8>,
+        pub required_signatures: Vec<AccountId>,
+        pub signatures: Vec<(AccountId, Vec<u8>)>,
+        pub timestamp: u64,
+        pub status: PaymentStatus,
+    }
+
+    impl<AccountId: Clone> InterCountryPayment<AccountId> {
+        /// Current layout, initiated by the sending government account itself and needing
+        /// `approval_threshold` signatures, the sender's threshold under version 1
+        pub fn upgrade(self, approval_threshold: u32) -> crate::InterCountryPayment<AccountId> {
+            crate::InterCountryPayment {
+                payment_id: self.payment_id,
+                initiator: self.from_government.clone(),
+                from_government: self.from_government,
+                to_government: self.to_government,
+                amount: self.amount,
+                purpose: self.purpose,
+                reference: self.reference,
+                required_signatures: self.required_signatures,
+                required_roles: Vec::new(),
+                signatures: self.signatures,
+                approval_threshold,
+                exceeds_limits: false,
+                recipient_country: None,
+                auto_execute: false,
+                failure_reason: None,
+                timestamp: self.timestamp,
+                status: self.status,
+            }
+        }
+    }
+}
+
+/// Version 2: governments and payments move to the current layout, country codes are normalised
+/// to ISO 3166-1 alpha-3 form and the country lookup is keyed by `(country_code, institution_type)`.
+pub mod v2 {
+    use super::*;
+
+    /// Decodes every government and payment with its [`v1`] layout and upgrades it, uppercases
+    /// and trims every stored country code, then rebuilds `CountryToAccount` from the government
+    /// registry and seeds `ActiveGovernmentCount` and, with the normalised codes of the registered
+    /// governments, `ValidCountryCodes`.
+    ///
+    /// Payments keep the signature threshold their sender had under version 1. Codes that are
+    /// still not three uppercase letters after normalisation are kept as they are and logged, so
+    /// governance can correct them. Every government still on a keyed account is logged too, as
+    /// it has to rotate to a keyless one, see [`v1`].
+    pub struct NormaliseCountryCodes<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for NormaliseCountryCodes<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 1,
+                "v2 migration requires storage version 1"
+            );
+            let governments = GovernmentRegistry::<T>::iter_keys().count() as u32;
+            let payments = PaymentRegistry::<T>::iter_keys().count() as u32;
+            Ok((governments, payments).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain = Pallet::<T>::on_chain_storage_version();
+            if on_chain != 1 {
+                log::info!(
+                    target: LOG_TARGET,
+                    "skipping v2 migration: on-chain storage version is {:?}",
+                    on_chain
+                );
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut reads = 1u64;
+            let mut writes = 0u64;
+
+            GovernmentRegistry::<T>::translate::<v1::GovernmentInfo<T::AccountId>, _>(|account, old| {
+                reads += 1;
+                writes += 1;
+                let mut gov = old.upgrade();
+                gov.country_code = Pallet::<T>::normalise_country_code(&gov.country_code);
+                let keyless = Pallet::<T>::government_account_id(&gov.country_code, &gov.institution_type, 0);
+                if account != keyless {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "government {:?} keeps its keyed version 1 account; its signatories must \
+                        rotate it to a keyless account with GovernmentAction::RotateAccount",
+                        account
+                    );
+                }
+                if gov.signature_threshold as usize >= gov.authorized_signatories.len() {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "government {:?} needs every signatory to approve; payments its signatories \
+                        initiate cannot be authorized until the signatories are updated",
+                        account
+                    );
+                }
+                Some(gov)
+            });
+
+            // The old lookup was keyed by country code only; rebuild it from the registry. The
+            // count of Active governments is maintained incrementally from now on.
+            let _ = CountryToAccount::<T>::clear(u32::MAX, None);
+            let mut active = 0u32;
+            for (account, gov) in GovernmentRegistry::<T>::iter() {
+                reads += 1;
+                if gov.status == GovernmentStatus::Active {
+                    active += 1;
+                }
+                let valid = Pallet::<T>::is_iso_alpha3(&gov.country_code);
+                if valid {
+                    ValidCountryCodes::<T>::insert(&gov.country_code, ());
+                    writes += 1;
+                }
+                if gov.status == GovernmentStatus::Pending {
+                    continue;
+                }
+                if !valid {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "government {:?} has invalid country code {:?}",
+                        account,
+                        gov.country_code
+                    );
+                }
+                if CountryToAccount::<T>::contains_key(&gov.country_code, &gov.institution_type) {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "duplicate institution {:?} for country {:?}, keeping {:?}",
+                        gov.institution_type,
+                        gov.country_code,
+                        account
+                    );
+                }
+                CountryToAccount::<T>::insert(&gov.country_code, &gov.institution_type, &account);
+                writes += 1;
+            }
+            ActiveGovernmentCount::<T>::put(active);
+            writes += 1;
+
+            PaymentRegistry::<T>::translate_values(|old: v1::InterCountryPayment<T::AccountId>| {
+                reads += 2;
+                writes += 1;
+                let threshold = Pallet::<T>::government_info(&old.from_government)
+                    .map_or(old.required_signatures.len() as u32, |gov| gov.signature_threshold);
+                let mut payment = old.upgrade(threshold);
+                if let PaymentPurpose::DevelopmentAid { beneficiary_country, .. } = &mut payment.purpose {
+                    *beneficiary_country = Pallet::<T>::normalise_country_code(beneficiary_country);
+                }
+                Some(payment)
+            });
+
+            let contributions: Vec<_> = ContributionsReceived::<T>::drain().collect();
+            for (organization, (member_state, contribution_type), amount) in contributions {
+                reads += 1;
+                writes += 2;
+                ContributionsReceived::<T>::mutate(
+                    &organization,
+                    (Pallet::<T>::normalise_country_code(&member_state), contribution_type),
+                    |total| *total = total.saturating_add(amount),
+                );
+            }
+
+            StorageVersion::new(2).put::<Pallet<T>>();
+            writes += 1;
+
+            log::info!(target: LOG_TARGET, "migrated governments and payments to storage version 2");
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let (governments, payments): (u32, u32) =
+                Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 2,
+                "storage version not updated"
+            );
+            ensure!(
+                GovernmentRegistry::<T>::iter_values().count() as u32 == governments,
+                "government lost or not decodable"
+            );
+            ensure!(
+                PaymentRegistry::<T>::iter_values().count() as u32 == payments,
+                "payment lost or not decodable"
+            );
+            ensure!(
+                GovernmentRegistry::<T>::iter_values()
+                    .all(|gov| gov.country_code == Pallet::<T>::normalise_country_code(&gov.country_code)),
+                "country code not normalised"
+            );
+            ensure!(
+                GovernmentRegistry::<T>::iter_values().all(|gov| {
+                    gov.status == GovernmentStatus::Pending
+                        || CountryToAccount::<T>::contains_key(&gov.country_code, &gov.institution_type)
+                }),
+                "country lookup not rebuilt"
+            );
+            ensure!(
+                GovernmentRegistry::<T>::iter_values().all(|gov| {
+                    !Pallet::<T>::is_iso_alpha3(&gov.country_code)
+                        || ValidCountryCodes::<T>::contains_key(&gov.country_code)
+                }),
+                "valid country codes not seeded"
+            );
+            ensure!(
+                Pallet::<T>::active_government_count() as usize
+                    == GovernmentRegistry::<T>::iter_values()
+                        .filter(|gov| gov.status == GovernmentStatus::Active)
+                        .count(),
+                "active government count not seeded"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Version 3: the funds of unsettled payments are held in per-payment escrow accounts instead
+/// of being reserved on the sending government's account.
+pub mod v3 {
+    use super::*;
+
+    /// Unreserves the amount and fee of every pending, authorized or frozen payment from its
+    /// sender and moves them into the payment's escrow account.
+    ///
+    /// Payments whose sender no longer has the funds reserved are logged and left as they are.
+    /// `OldCurrency` is the reservable currency the pallet was configured with before version 4.
+    pub struct MoveReservesToEscrow<T, OldCurrency>(PhantomData<(T, OldCurrency)>);
+
+    impl<T, OldCurrency> OnRuntimeUpgrade for MoveReservesToEscrow<T, OldCurrency>
+    where
+        T: Config,
+        OldCurrency: ReservableCurrency<T::AccountId>,
+    {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain = Pallet::<T>::on_chain_storage_version();
+            if on_chain != 2 {
+                log::info!(
+                    target: LOG_TARGET,
+                    "skipping v3 migration: on-chain storage version is {:?}",
+                    on_chain
+                );
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut reads = 1u64;
+            let mut writes = 0u64;
+            let fee = T::GovernmentTransactionFee::get();
+
+            for payment in PaymentRegistry::<T>::iter_values() {
+                reads += 1;
+                if !matches!(
+                    payment.status,
+                    PaymentStatus::Pending | PaymentStatus::Authorized | PaymentStatus::Frozen
+                ) {
+                    continue;
+                }
+
+                let total = payment.amount.saturating_add(fee).saturated_into();
+                let missing = OldCurrency::unreserve(&payment.from_government, total);
+                let moved = OldCurrency::transfer(
+                    &payment.from_government,
+                    &Pallet::<T>::payment_escrow_account(payment.payment_id),
+                    total,
+                    frame_support::traits::ExistenceRequirement::AllowDeath,
+                );
+                writes += 3;
+                if !missing.is_zero() || moved.is_err() {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "payment {} could not be fully moved to escrow",
+                        payment.payment_id
+                    );
+                }
+            }
+
+            StorageVersion::new(3).put::<Pallet<T>>();
+            writes += 1;
+
+            log::info!(target: LOG_TARGET, "moved in-flight payment funds to escrow, storage version 3");
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 3,
+                "storage version not updated"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Version 4: funds are held under pallet-specific hold reasons instead of being reserved or
+/// left free.
+pub mod v4 {
+    use super::*;
+
+    /// Converts the reserved deposits of open membership applications into `EscrowHold` holds
+    /// and puts the escrowed funds of unsettled payments on hold as `PaymentInFlight`.
+    ///
+    /// `OldCurrency` is the reservable currency the pallet was configured with before this
+    /// version. Amounts that cannot be converted are logged and left as they are.
+    pub struct ReservesToHolds<T, OldCurrency>(PhantomData<(T, OldCurrency)>);
+
+    impl<T, OldCurrency> OnRuntimeUpgrade for ReservesToHolds<T, OldCurrency>
+    where
+        T: Config,
+        OldCurrency: ReservableCurrency<T::AccountId>,
+    {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain = Pallet::<T>::on_chain_storage_version();
+            if on_chain != 3 {
+                log::info!(
+                    target: LOG_TARGET,
+                    "skipping v4 migration: on-chain storage version is {:?}",
+                    on_chain
+                );
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut reads = 1u64;
+            let mut writes = 0u64;
+
+            for (candidate, application) in MembershipApplications::<T>::iter() {
+                reads += 1;
+                writes += 2;
+                let missing = OldCurrency::unreserve(&application.depositor, application.deposit.saturated_into());
+                let held = T::Currency::hold(
+                    &HoldReason::EscrowHold.into(),
+                    &application.depositor,
+                    application.deposit.saturated_into(),
+                );
+                if !missing.is_zero() || held.is_err() {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "membership deposit of candidate {:?} could not be converted to a hold",
+                        candidate
+                    );
+                }
+            }
+
+            let fee = T::GovernmentTransactionFee::get();
+            for payment in PaymentRegistry::<T>::iter_values() {
+                reads += 1;
+                if !matches!(
+                    payment.status,
+                    PaymentStatus::Pending | PaymentStatus::Authorized | PaymentStatus::Frozen
+                ) {
+                    continue;
+                }
+
+                // As in `fund_escrow`, a provider reference keeps the escrow alive once its whole
+                // balance is on hold
+                writes += 2;
+                let escrow = Pallet::<T>::payment_escrow_account(payment.payment_id);
+                frame_system::Pallet::<T>::inc_providers(&escrow);
+                let held = T::Currency::hold(
+                    &HoldReason::PaymentInFlight.into(),
+                    &escrow,
+                    payment.amount.saturating_add(fee).saturated_into(),
+                );
+                if held.is_err() {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "escrow of payment {} could not be put on hold",
+                        payment.payment_id
+                    );
+                }
+            }
+
+            StorageVersion::new(4).put::<Pallet<T>>();
+            writes += 1;
+
+            log::info!(target: LOG_TARGET, "converted reserves to holds, storage version 4");
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 4,
+                "storage version not updated"
+            );
+            Pallet::<T>::check_escrow_invariant().map(|_| ()).map_err(Into::into)
+        }
+    }
+}
+
+/// Version 5: non-empty payment references are unique per sending government and indexed in
+/// `PaymentReferences`.
+pub mod v5 {
+    use super::*;
+
+    /// Builds `PaymentReferences` from the existing payments.
+    ///
+    /// Where a government used a reference more than once, the earliest payment is indexed and
+    /// the later ones are logged.
+    pub struct BuildReferenceIndex<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for BuildReferenceIndex<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain = Pallet::<T>::on_chain_storage_version();
+            if on_chain != 4 {
+                log::info!(
+                    target: LOG_TARGET,
+                    "skipping v5 migration: on-chain storage version is {:?}",
+                    on_chain
+                );
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut reads = 1u64;
+            let mut writes = 0u64;
+
+            let mut payments: Vec<_> = PaymentRegistry::<T>::iter_values()
+                .filter(|payment| !payment.reference.is_empty())
+                .collect();
+            reads += payments.len() as u64;
+            payments.sort_by_key(|payment| payment.payment_id);
+
+            for payment in payments {
+                reads += 1;
+                if let Some(first) = PaymentReferences::<T>::get(&payment.from_government, &payment.reference) {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "payment {} duplicates the reference of payment {}",
+                        payment.payment_id,
+                        first
+                    );
+                    continue;
+                }
+                PaymentReferences::<T>::insert(&payment.from_government, &payment.reference, payment.payment_id);
+                writes += 1;
+            }
+
+            StorageVersion::new(5).put::<Pallet<T>>();
+            writes += 1;
+
+            log::info!(target: LOG_TARGET, "indexed payment references, storage version 5");
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 5,
+                "storage version not updated"
+            );
+            ensure!(
+                PaymentRegistry::<T>::iter_values()
+                    .filter(|payment| !payment.reference.is_empty())
+                    .all(|payment| {
+                        PaymentReferences::<T>::contains_key(&payment.from_government, &payment.reference)
+                    }),
+                "payment reference not indexed"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Version 6: `SignatoryGovernments` indexes the governments each account signs for.
+pub mod v6 {
+    use super::*;
+
+    /// Builds `SignatoryGovernments` from the government registry.
+    pub struct BuildSignatoryIndex<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for BuildSignatoryIndex<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain = Pallet::<T>::on_chain_storage_version();
+            if on_chain != 5 {
+                log::info!(
+                    target: LOG_TARGET,
+                    "skipping v6 migration: on-chain storage version is {:?}",
+                    on_chain
+                );
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut reads = 1u64;
+            let mut writes = 0u64;
+
+            for (government, gov) in GovernmentRegistry::<T>::iter() {
+                reads += 1;
+                for signatory in gov.authorized_signatories {
+                    SignatoryGovernments::<T>::insert(&signatory.account, &government, ());
+                    writes += 1;
+                }
+            }
+
+            StorageVersion::new(6).put::<Pallet<T>>();
+            writes += 1;
+
+            log::info!(target: LOG_TARGET, "indexed government signatories, storage version 6");
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 6,
+                "storage version not updated"
+            );
+            ensure!(
+                GovernmentRegistry::<T>::iter().all(|(government, gov)| {
+                    gov.authorized_signatories
+                        .iter()
+                        .all(|signatory| SignatoryGovernments::<T>::contains_key(&signatory.account, &government))
+                }),
+                "signatory not indexed"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Version 7: organisations move to keyless accounts derived from their code and are controlled
+/// by role-typed signatories.
+pub mod v7 {
+    use super::*;
+
+    /// Version 6 organisation registration information, keyed by the organisation's own account
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct OrganizationInfo {
+        pub organization_code: Vec<u8>,
+        pub organization_name: Vec<u8>,
+        pub status: GovernmentStatus,
+        pub registered_at: u64,
+    }
+
+    #[frame_support::storage_alias]
+    pub type OrganizationRegistry<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        OrganizationInfo,
+    >;
+
+    /// Moves every organisation, its contribution statement and payment history to the account
+    /// [`Pallet::organization_account_id`] derives from its code, and points the payments to it
+    /// at that account.
+    ///
+    /// Migrated organisations have no signatories, so nothing can leave their new account until
+    /// `set_organization_signatories` gives them some; each one is logged. Funds already paid to
+    /// an old account stay with the key that controls it.
+    pub struct DeriveOrganizationAccounts<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for DeriveOrganizationAccounts<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let organizations = OrganizationRegistry::<T>::iter_keys().count() as u32;
+            Ok(organizations.encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain = Pallet::<T>::on_chain_storage_version();
+            if on_chain != 6 {
+                log::info!(
+                    target: LOG_TARGET,
+                    "skipping v7 migration: on-chain storage version is {:?}",
+                    on_chain
+                );
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut reads = 1u64;
+            let mut writes = 0u64;
+
+            let organizations: Vec<_> = OrganizationRegistry::<T>::drain().collect();
+            for (old, org) in organizations {
+                reads += 1;
+                let new = Pallet::<T>::organization_account_id(&org.organization_code);
+                log::warn!(
+                    target: LOG_TARGET,
+                    "organisation {:?} moved from {:?} to keyless account {:?}; it has no \
+                    signatories until set_organization_signatories is called",
+                    org.organization_code,
+                    old,
+                    new
+                );
+
+                crate::OrganizationRegistry::<T>::insert(
+                    &new,
+                    crate::OrganizationInfo {
+                        organization_code: org.organization_code.clone(),
+                        organization_name: org.organization_name,
+                        status: org.status,
+                        signature_threshold: 0,
+                        authorized_signatories: Vec::new(),
+                        registered_at: org.registered_at,
+                    },
+                );
+                OrganizationToAccount::<T>::insert(&org.organization_code, &new);
+                writes += 2;
+
+                let contributions: Vec<_> = ContributionsReceived::<T>::drain_prefix(&old).collect();
+                for (key, amount) in contributions {
+                    reads += 1;
+                    writes += 2;
+                    ContributionsReceived::<T>::insert(&new, key, amount);
+                }
+
+                reads += 1;
+                let history = GovernmentPaymentHistory::<T>::take(&old).unwrap_or_default();
+                for payment_id in &history {
+                    reads += 1;
+                    PaymentRegistry::<T>::mutate(payment_id, |payment| {
+                        if let Some(payment) = payment.as_mut().filter(|payment| payment.to_government == old) {
+                            payment.to_government = new.clone();
+                            writes += 1;
+                        }
+                    });
+                }
+                if !history.is_empty() {
+                    GovernmentPaymentHistory::<T>::insert(&new, history);
+                    writes += 2;
+                }
+            }
+
+            StorageVersion::new(7).put::<Pallet<T>>();
+            writes += 1;
+
+            log::info!(target: LOG_TARGET, "moved organisations to derived accounts, storage version 7");
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let organizations: u32 =
+                Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 7,
+                "storage version not updated"
+            );
+            ensure!(
+                crate::OrganizationRegistry::<T>::iter_values().count() as u32 == organizations,
+                "organisation lost or not decodable"
+            );
+            ensure!(
+                crate::OrganizationRegistry::<T>::iter().all(|(account, org)| {
+                    account == Pallet::<T>::organization_account_id(&org.organization_code)
+                }),
+                "organisation account not derived"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Migration to version 8, indexing payments in flight and totalling completed ones per party
+pub mod v8 {
+    use super::*;
+
+    /// Fills `UnsettledPayments` with the pending, authorized, frozen and failed payments of each
+    /// party and `PaymentTotals` with the amounts each party sent and received in completed
+    /// payments.
+    ///
+    /// Payments beyond `MaxUnsettledPayments` for a party are left out of its index and logged;
+    /// they still settle or can be cancelled, but status changes and rotations of that party do
+    /// not reach them.
+    pub struct IndexUnsettledPayments<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for IndexUnsettledPayments<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let completed = PaymentRegistry::<T>::iter_values()
+                .filter(|payment| payment.status == PaymentStatus::Completed)
+                .fold(0u128, |total, payment| total.saturating_add(payment.amount));
+            Ok(completed.encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain = Pallet::<T>::on_chain_storage_version();
+            if on_chain != 7 {
+                log::info!(
+                    target: LOG_TARGET,
+                    "skipping v8 migration: on-chain storage version is {:?}",
+                    on_chain
+                );
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut reads = 1u64;
+            let mut writes = 0u64;
+
+            for payment in PaymentRegistry::<T>::iter_values() {
+                reads += 1;
+                match payment.status {
+                    PaymentStatus::Completed => {
+                        PaymentTotals::<T>::mutate(&payment.from_government, |(sent, _)| {
+                            *sent = sent.saturating_add(payment.amount)
+                        });
+                        PaymentTotals::<T>::mutate(&payment.to_government, |(_, received)| {
+                            *received = received.saturating_add(payment.amount)
+                        });
+                    }
+                    PaymentStatus::Cancelled => continue,
+                    _ => {
+                        for party in [&payment.from_government, &payment.to_government] {
+                            if UnsettledPayments::<T>::mutate(party, |ids| ids.try_push(payment.payment_id)).is_err() {
+                                log::warn!(
+                                    target: LOG_TARGET,
+                                    "payment {} not indexed: {:?} has more than MaxUnsettledPayments in flight",
+                                    payment.payment_id,
+                                    party
+                                );
+                            }
+                        }
+                    }
+                }
+                reads += 2;
+                writes += 2;
+            }
+
+            StorageVersion::new(8).put::<Pallet<T>>();
+            writes += 1;
+
+            log::info!(target: LOG_TARGET, "indexed unsettled payments, storage version 8");
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let completed: u128 = Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 8,
+                "storage version not updated"
+            );
+            ensure!(
+                PaymentTotals::<T>::iter_values().fold(0u128, |total, (sent, _)| total.saturating_add(sent))
+                    == completed,
+                "completed payments not totalled"
+            );
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Test runtime for the inter-country payments pallet
+
+use crate as pallet_inter_country_payments;
+use crate::EnsureGovernmentSupermajority;
+use frame_support::{
+    parameter_types,
+    traits::{ConstU128, ConstU32, ConstU64, EitherOfDiverse, Everything, Nothing},
+    PalletId,
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    AccountId32, BuildStorage, Perbill,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+/// Accounts are 32 bytes long so that derived government and escrow accounts do not collide
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+
+/// Balance minted into every government account the tests register
+pub const INITIAL_BALANCE: Balance = 10_000;
+/// `GovernmentTransactionFee` of the test runtime
+pub const FEE: Balance = 10;
+/// `MembershipDeposit` of the test runtime
+pub const DEPOSIT: Balance = 100;
+/// Account applying for membership in the tests, funded at genesis
+pub const APPLICANT: u8 = 100;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        // Pallets with an origin come first: the origin enum's `Void` variant takes the index
+        // after them, which must not clash with their pallet indices
+        System: frame_system,
+        InterCountryPayments: pallet_inter_country_payments,
+        Balances: pallet_balances,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<5>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type MaxHolds = ConstU32<3>;
+}
+
+parameter_types! {
+    pub const InterCountryPaymentsPalletId: PalletId = PalletId(*b"py/icpay");
+    pub const TreasuryAccount: AccountId = AccountId32::new([0xff; 32]);
+    pub const MembershipApprovalThreshold: Perbill = Perbill::from_percent(66);
+    pub static RotationDelay: u64 = 0;
+}
+
+impl pallet_inter_country_payments::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type MaxCountryCodeLength = ConstU32<3>;
+    type MaxReferenceLength = ConstU32<32>;
+    type MaxOrganizationCodeLength = ConstU32<8>;
+    type MaxApprovalTiers = ConstU32<4>;
+    type MinSignatories = ConstU32<5>;
+    type MinSignatureThreshold = ConstU32<2>;
+    type GovernmentTransactionFee = ConstU128<FEE>;
+    type PalletId = InterCountryPaymentsPalletId;
+    type RegistrationOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureGovernmentSupermajority>;
+    type StatusOrigin = EnsureRoot<AccountId>;
+    type Proposal = RuntimeCall;
+    type MotionVotingPeriod = ConstU64<10>;
+    type MembershipDeposit = ConstU128<DEPOSIT>;
+    type MembershipVotingPeriod = ConstU64<10>;
+    type MembershipApprovalThreshold = MembershipApprovalThreshold;
+    type DailyLimitPeriod = ConstU64<100>;
+    type MonthlyLimitPeriod = ConstU64<3_000>;
+    type RotationDelay = RotationDelay;
+    type MaxUnsettledPayments = ConstU32<8>;
+    type TreasuryAccount = TreasuryAccount;
+    type ExemptCalls = Nothing;
+    type MaxFeelessCalls = ConstU32<3>;
+    type FeelessCallWindow = ConstU64<100>;
+    type UrgentPaymentPriority = ConstU64<1_000>;
+}
+
+/// Test account derived from a single byte
+pub fn account(n: u8) -> AccountId {
+    AccountId32::new([n; 32])
+}
+
+/// Externalities with the country codes used by the tests and a funded membership applicant,
+/// at block 1
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+    pallet_balances::GenesisConfig::<Test> { balances: vec![(account(APPLICANT), 1_000)] }
+        .assimilate_storage(&mut storage)
+        .unwrap();
+
+    pallet_inter_country_payments::GenesisConfig::<Test> {
+        valid_country_codes: [b"FRA", b"DEU", b"JPN", b"ITA", b"BRA"].iter().map(|code| code.to_vec()).collect(),
+        allowed_callers: Vec::new(),
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,1751 @@
+//! Tests for the inter-country payments pallet
+
+use crate::{migrations, mock::*, *};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::{DispatchResultWithPostInfo, GetDispatchInfo, Pays},
+    storage::unhashed,
+    traits::{
+        fungible::{Inspect, InspectHold, Mutate, MutateHold},
+        tokens::Preservation,
+        GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency, StorageVersion,
+    },
+    weights::Weight,
+};
+use sp_runtime::{
+    traits::{BlakeTwo256, Hash, SignedExtension},
+    transaction_validity::InvalidTransaction,
+};
+
+/// First signatory account of each test government
+const FRA: u8 = 10;
+const DEU: u8 = 20;
+const JPN: u8 = 30;
+const ITA: u8 = 40;
+const WHO: u8 = 80;
+
+/// An initiator (`base`), three approvers (`base + 1` to `base + 3`) and a releaser (`base + 4`)
+fn signatories(base: u8) -> Vec<Signatory<AccountId>> {
+    let signatory = |offset: u8, role: SignatoryRole| Signatory { account: account(base + offset), roles: vec![role] };
+    vec![
+        signatory(0, SignatoryRole::Initiator),
+        signatory(1, SignatoryRole::Approver),
+        signatory(2, SignatoryRole::Approver),
+        signatory(3, SignatoryRole::Approver),
+        signatory(4, SignatoryRole::Releaser),
+    ]
+}
+
+fn register_call(
+    code: &[u8],
+    institution_type: InstitutionType,
+    signatories: Vec<Signatory<AccountId>>,
+    signature_threshold: u32,
+) -> DispatchResult {
+    InterCountryPayments::register_government(
+        RuntimeOrigin::root(),
+        code.to_vec(),
+        b"Treasury".to_vec(),
+        institution_type,
+        signature_threshold,
+        signatories,
+        Vec::new(),
+    )
+}
+
+/// Register the treasury of `code` with [`signatories`] from `base` and fund its account
+fn register_with(code: &[u8], base: u8, approval_tiers: Vec<ApprovalTier>) -> AccountId {
+    assert_ok!(InterCountryPayments::register_government(
+        RuntimeOrigin::root(),
+        code.to_vec(),
+        b"Treasury".to_vec(),
+        InstitutionType::Treasury,
+        3,
+        signatories(base),
+        approval_tiers,
+    ));
+    let government = InterCountryPayments::country_to_account(code.to_vec(), InstitutionType::Treasury).unwrap();
+    assert_ok!(Balances::mint_into(&government, INITIAL_BALANCE));
+    government
+}
+
+fn register(code: &[u8], base: u8) -> AccountId {
+    register_with(code, base, Vec::new())
+}
+
+fn trade() -> PaymentPurpose {
+    PaymentPurpose::TradeSettlement {
+        trade_agreement_id: b"TA-1".to_vec(),
+        goods_reference: b"GR-1".to_vec(),
+    }
+}
+
+fn initiate_call(from: &AccountId, base: u8, to: &AccountId, amount: u128) -> DispatchResultWithPostInfo {
+    InterCountryPayments::initiate_payment(
+        RuntimeOrigin::signed(account(base)),
+        from.clone(),
+        to.clone(),
+        amount,
+        trade(),
+        Vec::new(),
+        false,
+    )
+}
+
+/// Initiate a trade payment through the initiator of `from`, returning its id
+fn initiate(from: &AccountId, base: u8, to: &AccountId, amount: u128) -> u64 {
+    let payment_id = InterCountryPayments::next_payment_id();
+    assert_ok!(initiate_call(from, base, to, amount));
+    payment_id
+}
+
+/// Sign a payment by all three approvers of the sending government
+fn authorize(payment_id: u64, base: u8) {
+    for approver in 1..=3 {
+        assert_ok!(InterCountryPayments::sign_payment(
+            RuntimeOrigin::signed(account(base + approver)),
+            payment_id,
+            b"signature".to_vec(),
+        ));
+    }
+}
+
+fn execute(payment_id: u64, base: u8) {
+    assert_ok!(InterCountryPayments::execute_payment(RuntimeOrigin::signed(account(base + 4)), payment_id));
+}
+
+/// Propose a government action and approve it up to the threshold
+fn act(government: &AccountId, base: u8, action: GovernmentAction<AccountId>) {
+    let proposal_id = InterCountryPayments::next_government_proposal_id(government);
+    assert_ok!(InterCountryPayments::propose_government_action(
+        RuntimeOrigin::signed(account(base + 1)),
+        government.clone(),
+        action,
+    ));
+    for approver in [base + 2, base + 3] {
+        assert_ok!(InterCountryPayments::approve_government_action(
+            RuntimeOrigin::signed(account(approver)),
+            government.clone(),
+            proposal_id,
+        ));
+    }
+    assert!(InterCountryPayments::government_proposal(government, proposal_id).is_none());
+}
+
+fn status(payment_id: u64) -> PaymentStatus {
+    InterCountryPayments::payment_info(payment_id).unwrap().status
+}
+
+fn escrow(payment_id: u64) -> AccountId {
+    InterCountryPayments::payment_escrow_account(payment_id)
+}
+
+fn held(reason: HoldReason, who: &AccountId) -> u128 {
+    Balances::balance_on_hold(&reason.into(), who)
+}
+
+fn add_country_code(code: &[u8]) -> RuntimeCall {
+    RuntimeCall::InterCountryPayments(crate::Call::add_country_code { country_codes: vec![code.to_vec()] })
+}
+
+fn rename(government_name: &[u8]) -> GovernmentProfileUpdate {
+    GovernmentProfileUpdate {
+        government_name: Some(government_name.to_vec()),
+        institution_type: None,
+        lei: None,
+        bic: None,
+        website_hash: None,
+    }
+}
+
+/// Payment in the current layout, as a migration finds it
+fn stored_payment(payment_id: u64, from: &AccountId, to: &AccountId, reference: &[u8]) -> InterCountryPayment<AccountId> {
+    InterCountryPayment {
+        payment_id,
+        from_government: from.clone(),
+        to_government: to.clone(),
+        initiator: account(FRA),
+        amount: 1_000,
+        purpose: trade(),
+        reference: reference.to_vec(),
+        required_signatures: Vec::new(),
+        required_roles: Vec::new(),
+        signatures: Vec::new(),
+        approval_threshold: 3,
+        exceeds_limits: false,
+        recipient_country: None,
+        auto_execute: false,
+        failure_reason: None,
+        timestamp: 0,
+        status: PaymentStatus::Pending,
+    }
+}
+
+/// Apply for membership of the treasury of `code` with [`signatories`] from `base`
+fn apply(code: &[u8], base: u8) -> AccountId {
+    assert_ok!(InterCountryPayments::apply_for_membership(
+        RuntimeOrigin::signed(account(APPLICANT)),
+        code.to_vec(),
+        b"Treasury".to_vec(),
+        InstitutionType::Treasury,
+        3,
+        signatories(base),
+        Vec::new(),
+    ));
+    InterCountryPayments::government_account_id(code, &InstitutionType::Treasury, 0)
+}
+
+#[test]
+fn payment_moves_through_escrow_to_recipient() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        let issuance = Balances::total_issuance();
+
+        let payment_id = initiate(&fra, FRA, &deu, 1_000);
+        let escrow = escrow(payment_id);
+        assert_eq!(Balances::balance(&fra), INITIAL_BALANCE - 1_000 - FEE);
+        assert_eq!(held(HoldReason::PaymentInFlight, &escrow), 1_000 + FEE);
+        assert_eq!(Balances::balance(&escrow), 0);
+        assert_eq!(InterCountryPayments::check_escrow_invariant(), Ok(1_000 + FEE));
+
+        authorize(payment_id, FRA);
+        assert_eq!(status(payment_id), PaymentStatus::Authorized);
+
+        execute(payment_id, FRA);
+        assert_eq!(status(payment_id), PaymentStatus::Completed);
+        assert_eq!(Balances::balance(&deu), INITIAL_BALANCE + 1_000);
+        assert_eq!(Balances::total_balance(&escrow), 0);
+        assert!(!System::account_exists(&escrow));
+        assert_eq!(Balances::total_issuance(), issuance - FEE);
+        assert_eq!(InterCountryPayments::total_payments_volume(), 1_000);
+        assert_eq!(InterCountryPayments::get_government_stats(&fra), Some((1, 1_000, 0)));
+    });
+}
+
+#[test]
+fn cancellation_refunds_escrow() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        let payment_id = initiate(&fra, FRA, &deu, 1_000);
+
+        assert_noop!(
+            InterCountryPayments::cancel_payment(RuntimeOrigin::signed(account(FRA + 1)), payment_id, Vec::new()),
+            Error::<Test>::MissingRole
+        );
+        assert_ok!(InterCountryPayments::cancel_payment(
+            RuntimeOrigin::signed(account(FRA)),
+            payment_id,
+            b"duplicate".to_vec(),
+        ));
+
+        assert_eq!(status(payment_id), PaymentStatus::Cancelled);
+        assert_eq!(Balances::balance(&fra), INITIAL_BALANCE);
+        assert!(!System::account_exists(&escrow(payment_id)));
+        assert_eq!(InterCountryPayments::check_escrow_invariant(), Ok(0));
+    });
+}
+
+#[test]
+fn v4_migration_converts_reserves_and_escrow_to_holds() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        PaymentRegistry::<Test>::insert(0, stored_payment(0, &fra, &deu, b""));
+        assert_ok!(<Balances as Mutate<AccountId>>::transfer(
+            &fra,
+            &escrow(0),
+            1_000 + FEE,
+            Preservation::Expendable,
+        ));
+        let candidate = account(90);
+        MembershipApplications::<Test>::insert(
+            &candidate,
+            MembershipApplication {
+                depositor: account(APPLICANT),
+                deposit: DEPOSIT,
+                ayes: Vec::new(),
+                nays: Vec::new(),
+                submitted_at: 1,
+                end: 11,
+            },
+        );
+        assert_ok!(<Balances as ReservableCurrency<AccountId>>::reserve(&account(APPLICANT), DEPOSIT));
+        StorageVersion::new(3).put::<InterCountryPayments>();
+
+        migrations::v4::ReservesToHolds::<Test, Balances>::on_runtime_upgrade();
+
+        assert_eq!(InterCountryPayments::on_chain_storage_version(), 4);
+        assert_eq!(held(HoldReason::EscrowHold, &account(APPLICANT)), DEPOSIT);
+        assert_eq!(Balances::balance(&account(APPLICANT)), 1_000 - DEPOSIT);
+        assert_eq!(held(HoldReason::PaymentInFlight, &escrow(0)), 1_000 + FEE);
+        assert_eq!(Balances::balance(&escrow(0)), 0);
+        assert_eq!(InterCountryPayments::check_escrow_invariant(), Ok(1_000 + FEE));
+
+        // The converted escrow settles like one funded after the upgrade
+        PaymentRegistry::<Test>::mutate(0, |payment| payment.as_mut().unwrap().status = PaymentStatus::Authorized);
+        execute(0, FRA);
+        assert_eq!(status(0), PaymentStatus::Completed);
+        assert_eq!(Balances::balance(&deu), INITIAL_BALANCE + 1_000);
+        assert!(!System::account_exists(&escrow(0)));
+    });
+}
+
+#[test]
+fn organisation_receives_contributions() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let who = InterCountryPayments::organization_account_id(b"WHO");
+        assert_noop!(
+            InterCountryPayments::register_organization(
+                RuntimeOrigin::root(),
+                b"who".to_vec(),
+                Vec::new(),
+                3,
+                signatories(WHO),
+            ),
+            Error::<Test>::InvalidOrganizationCode
+        );
+        assert_noop!(
+            InterCountryPayments::register_organization(
+                RuntimeOrigin::root(),
+                b"WHO".to_vec(),
+                Vec::new(),
+                4,
+                signatories(WHO),
+            ),
+            Error::<Test>::SignatureThresholdTooHigh
+        );
+        assert_ok!(InterCountryPayments::register_organization(
+            RuntimeOrigin::root(),
+            b"WHO".to_vec(),
+            b"World Health Organization".to_vec(),
+            3,
+            signatories(WHO),
+        ));
+
+        let contribution = |organization: &[u8]| PaymentPurpose::InternationalContribution {
+            organization: organization.to_vec(),
+            contribution_type: ContributionType::MembershipFees,
+        };
+        assert_noop!(
+            InterCountryPayments::initiate_payment(
+                RuntimeOrigin::signed(account(FRA)),
+                fra.clone(),
+                who.clone(),
+                500,
+                contribution(b"UN"),
+                Vec::new(),
+                false,
+            ),
+            Error::<Test>::OrganizationMismatch
+        );
+
+        let payment_id = InterCountryPayments::next_payment_id();
+        assert_ok!(InterCountryPayments::initiate_payment(
+            RuntimeOrigin::signed(account(FRA)),
+            fra,
+            who.clone(),
+            500,
+            contribution(b"WHO"),
+            Vec::new(),
+            false,
+        ));
+        authorize(payment_id, FRA);
+        execute(payment_id, FRA);
+
+        assert_eq!(Balances::balance(&who), 500);
+        assert_eq!(
+            InterCountryPayments::get_organization_contributions(&who),
+            vec![(b"FRA".to_vec(), ContributionType::MembershipFees, 500)]
+        );
+    });
+}
+
+#[test]
+fn organisation_transfers_need_signatory_threshold() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(InterCountryPayments::register_organization(
+            RuntimeOrigin::root(),
+            b"WHO".to_vec(),
+            b"World Health Organization".to_vec(),
+            3,
+            signatories(WHO),
+        ));
+        let who = InterCountryPayments::organization_account_id(b"WHO");
+        assert_ok!(Balances::mint_into(&who, 1_000));
+        let dest = account(99);
+
+        assert_noop!(
+            InterCountryPayments::propose_organization_transfer(RuntimeOrigin::signed(account(WHO)), who.clone(), dest.clone(), 400),
+            Error::<Test>::MissingRole
+        );
+        assert_ok!(InterCountryPayments::propose_organization_transfer(
+            RuntimeOrigin::signed(account(WHO + 1)),
+            who.clone(),
+            dest.clone(),
+            400,
+        ));
+        assert_ok!(InterCountryPayments::approve_organization_transfer(
+            RuntimeOrigin::signed(account(WHO + 2)),
+            who.clone(),
+            0,
+        ));
+        assert_eq!(Balances::balance(&who), 1_000);
+
+        // Approvals of replaced signatories no longer count
+        let mut replaced = signatories(WHO);
+        replaced[1].account = account(WHO + 6);
+        assert_ok!(InterCountryPayments::set_organization_signatories(
+            RuntimeOrigin::root(),
+            who.clone(),
+            3,
+            replaced,
+        ));
+        assert_noop!(
+            InterCountryPayments::approve_organization_transfer(RuntimeOrigin::signed(account(WHO + 1)), who.clone(), 0),
+            Error::<Test>::NotAuthorizedSigner
+        );
+        assert_ok!(InterCountryPayments::approve_organization_transfer(
+            RuntimeOrigin::signed(account(WHO + 3)),
+            who.clone(),
+            0,
+        ));
+        assert_eq!(Balances::balance(&who), 1_000);
+
+        assert_ok!(InterCountryPayments::approve_organization_transfer(
+            RuntimeOrigin::signed(account(WHO + 6)),
+            who.clone(),
+            0,
+        ));
+        assert_eq!(Balances::balance(&who), 600);
+        assert_eq!(Balances::balance(&dest), 400);
+        assert!(InterCountryPayments::organization_transfer(&who, 0).is_none());
+    });
+}
+
+#[test]
+fn motion_dispatches_with_government_origin() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        let jpn = register(b"JPN", JPN);
+        assert_eq!(InterCountryPayments::active_government_count(), 3);
+
+        let call = add_country_code(b"ESP");
+        let motion_hash = BlakeTwo256::hash_of(&call);
+        let weight = call.get_dispatch_info().weight;
+        assert_ok!(InterCountryPayments::propose_motion(
+            RuntimeOrigin::signed(account(FRA + 1)),
+            fra,
+            Box::new(call),
+        ));
+        assert_ok!(InterCountryPayments::vote_motion(RuntimeOrigin::signed(account(DEU + 1)), deu, motion_hash, true));
+        assert_noop!(
+            InterCountryPayments::close_motion(RuntimeOrigin::signed(account(1)), motion_hash, weight),
+            Error::<Test>::MotionVotingOpen
+        );
+
+        assert_ok!(InterCountryPayments::vote_motion(RuntimeOrigin::signed(account(JPN + 1)), jpn, motion_hash, false));
+        assert_noop!(
+            InterCountryPayments::close_motion(RuntimeOrigin::signed(account(1)), motion_hash, Weight::zero()),
+            Error::<Test>::WrongProposalWeight
+        );
+        assert_ok!(InterCountryPayments::close_motion(RuntimeOrigin::signed(account(1)), motion_hash, weight));
+
+        System::assert_has_event(Event::<Test>::MotionExecuted { motion_hash, result: Ok(()) }.into());
+        assert!(InterCountryPayments::is_listed_country_code(b"ESP".to_vec()).is_some());
+        assert!(InterCountryPayments::motion_votes(motion_hash).is_none());
+    });
+}
+
+#[test]
+fn active_government_count_follows_status() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        register(b"DEU", DEU);
+        assert_eq!(InterCountryPayments::active_government_count(), 2);
+
+        assert_ok!(InterCountryPayments::update_government_status(
+            RuntimeOrigin::root(),
+            fra.clone(),
+            GovernmentStatus::Suspended,
+        ));
+        assert_eq!(InterCountryPayments::active_government_count(), 1);
+
+        assert_ok!(InterCountryPayments::update_government_status(
+            RuntimeOrigin::root(),
+            fra,
+            GovernmentStatus::Active,
+        ));
+        assert_eq!(InterCountryPayments::active_government_count(), 2);
+    });
+}
+
+#[test]
+fn motion_votes_close_with_the_voting_period() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+
+        let call = add_country_code(b"ESP");
+        let motion_hash = BlakeTwo256::hash_of(&call);
+        assert_ok!(InterCountryPayments::propose_motion(
+            RuntimeOrigin::signed(account(FRA + 1)),
+            fra,
+            Box::new(call),
+        ));
+
+        System::set_block_number(11);
+        assert_noop!(
+            InterCountryPayments::vote_motion(RuntimeOrigin::signed(account(DEU + 1)), deu, motion_hash, true),
+            Error::<Test>::MotionVotingClosed
+        );
+    });
+}
+
+#[test]
+fn membership_vote_admits_candidate() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        register(b"JPN", JPN);
+
+        let free = Balances::balance(&account(APPLICANT));
+        let candidate = apply(b"ITA", ITA);
+        assert_eq!(InterCountryPayments::government_info(&candidate).unwrap().status, GovernmentStatus::Pending);
+        assert_eq!(held(HoldReason::EscrowHold, &account(APPLICANT)), DEPOSIT);
+
+        assert_ok!(InterCountryPayments::vote_on_membership(
+            RuntimeOrigin::signed(account(FRA + 1)),
+            fra,
+            candidate.clone(),
+            true,
+        ));
+        assert!(InterCountryPayments::membership_application(&candidate).is_some());
+        assert_ok!(InterCountryPayments::vote_on_membership(
+            RuntimeOrigin::signed(account(DEU + 1)),
+            deu.clone(),
+            candidate.clone(),
+            true,
+        ));
+
+        assert_eq!(InterCountryPayments::government_info(&candidate).unwrap().status, GovernmentStatus::Active);
+        assert_eq!(InterCountryPayments::active_government_count(), 4);
+        assert_eq!(held(HoldReason::EscrowHold, &account(APPLICANT)), 0);
+        assert_eq!(Balances::balance(&account(APPLICANT)), free);
+        assert_eq!(
+            InterCountryPayments::country_to_account(b"ITA".to_vec(), InstitutionType::Treasury),
+            Some(candidate.clone())
+        );
+        assert!(InterCountryPayments::membership_decisions(&candidate).unwrap()[0].approved);
+        assert!(InterCountryPayments::government_membership_vote(&deu, &candidate).is_none());
+    });
+}
+
+#[test]
+fn membership_votes_close_with_the_voting_period() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        register(b"DEU", DEU);
+        let free = Balances::balance(&account(APPLICANT));
+        let candidate = apply(b"ITA", ITA);
+
+        assert_noop!(
+            InterCountryPayments::close_membership_vote(RuntimeOrigin::signed(account(1)), candidate.clone()),
+            Error::<Test>::MembershipVotingOpen
+        );
+
+        System::set_block_number(11);
+        assert_noop!(
+            InterCountryPayments::vote_on_membership(
+                RuntimeOrigin::signed(account(FRA + 1)),
+                fra,
+                candidate.clone(),
+                true,
+            ),
+            Error::<Test>::MembershipVotingClosed
+        );
+
+        assert_ok!(InterCountryPayments::close_membership_vote(RuntimeOrigin::signed(account(1)), candidate.clone()));
+        assert!(InterCountryPayments::government_info(&candidate).is_none());
+        assert!(!InterCountryPayments::membership_decisions(&candidate).unwrap()[0].approved);
+        // The deposit of a rejected application is burnt
+        assert_eq!(held(HoldReason::EscrowHold, &account(APPLICANT)), 0);
+        assert_eq!(Balances::balance(&account(APPLICANT)), free - DEPOSIT);
+    });
+}
+
+#[test]
+fn pending_candidate_cannot_act() {
+    new_test_ext().execute_with(|| {
+        register(b"FRA", FRA);
+        let candidate = apply(b"ITA", ITA);
+
+        assert_noop!(
+            InterCountryPayments::propose_government_action(
+                RuntimeOrigin::signed(account(ITA + 1)),
+                candidate.clone(),
+                GovernmentAction::RotateAccount,
+            ),
+            Error::<Test>::GovernmentNotActive
+        );
+
+        // The open application decides the candidate's registration and status
+        assert_noop!(
+            register_call(b"ITA", InstitutionType::Treasury, signatories(ITA), 3),
+            Error::<Test>::MembershipApplicationOpen
+        );
+        for status in [GovernmentStatus::Active, GovernmentStatus::Revoked] {
+            assert_noop!(
+                InterCountryPayments::update_government_status(RuntimeOrigin::root(), candidate.clone(), status),
+                Error::<Test>::MembershipApplicationOpen
+            );
+        }
+    });
+}
+
+#[test]
+fn counterparty_rules_block_payments() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        let jpn = register(b"JPN", JPN);
+
+        act(&deu, DEU, GovernmentAction::SetCounterpartyRule {
+            counterparty: fra.clone(),
+            rule: Some(CounterpartyRule::Deny),
+        });
+        assert_noop!(initiate_call(&fra, FRA, &deu, 100), Error::<Test>::BlockedByCounterparty);
+        assert!(InterCountryPayments::counterparty_index(&fra, &deu).is_some());
+
+        act(&fra, FRA, GovernmentAction::SetCounterpartyMode { mode: CounterpartyMode::AllowListedOnly });
+        assert_noop!(initiate_call(&fra, FRA, &jpn, 100), Error::<Test>::CounterpartyBlocked);
+
+        act(&fra, FRA, GovernmentAction::SetCounterpartyRule {
+            counterparty: jpn.clone(),
+            rule: Some(CounterpartyRule::Allow),
+        });
+        assert_ok!(initiate_call(&fra, FRA, &jpn, 100));
+    });
+}
+
+#[test]
+fn approvals_of_removed_approvers_do_not_count() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+
+        let proposal_id = InterCountryPayments::next_government_proposal_id(&fra);
+        assert_ok!(InterCountryPayments::propose_government_action(
+            RuntimeOrigin::signed(account(FRA + 1)),
+            fra.clone(),
+            GovernmentAction::SetCounterpartyMode { mode: CounterpartyMode::AllowListedOnly },
+        ));
+
+        // The proposing approver is replaced before the proposal reaches the threshold
+        let mut replaced = signatories(FRA);
+        replaced[1].account = account(FRA + 6);
+        act(&fra, FRA, GovernmentAction::UpdateSignatories { signatories: replaced, signature_threshold: 3 });
+
+        for approver in [FRA + 2, FRA + 3] {
+            assert_ok!(InterCountryPayments::approve_government_action(
+                RuntimeOrigin::signed(account(approver)),
+                fra.clone(),
+                proposal_id,
+            ));
+        }
+        assert_eq!(InterCountryPayments::counterparty_mode(&fra), CounterpartyMode::DenyListed);
+        assert_eq!(
+            InterCountryPayments::government_proposal(&fra, proposal_id).unwrap().approvals,
+            vec![account(FRA + 2), account(FRA + 3)]
+        );
+
+        assert_ok!(InterCountryPayments::approve_government_action(
+            RuntimeOrigin::signed(account(FRA + 6)),
+            fra.clone(),
+            proposal_id,
+        ));
+        assert_eq!(InterCountryPayments::counterparty_mode(&fra), CounterpartyMode::AllowListedOnly);
+    });
+}
+
+#[test]
+fn spending_limits_cap_outflow() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+
+        act(&fra, FRA, GovernmentAction::SetSpendingLimits {
+            limits: SpendingLimits { max_single_payment: Some(500), daily_cap: Some(800), ..Default::default() },
+        });
+        assert_noop!(initiate_call(&fra, FRA, &deu, 600), Error::<Test>::SinglePaymentLimitExceeded);
+
+        let payment_id = initiate(&fra, FRA, &deu, 400);
+        authorize(payment_id, FRA);
+        execute(payment_id, FRA);
+        assert_eq!(InterCountryPayments::get_spending_utilisation(&fra).daily_spent, 400);
+        assert_noop!(initiate_call(&fra, FRA, &deu, 500), Error::<Test>::DailyLimitExceeded);
+
+        System::set_block_number(101);
+        assert_ok!(initiate_call(&fra, FRA, &deu, 500));
+    });
+}
+
+#[test]
+fn approval_tiers_set_signatures_by_amount() {
+    new_test_ext().execute_with(|| {
+        let tier = |required_approvals| ApprovalTier { amount_ceiling: 100, required_approvals, required_roles: Vec::new() };
+        assert_noop!(
+            InterCountryPayments::register_government(
+                RuntimeOrigin::root(),
+                b"FRA".to_vec(),
+                b"Treasury".to_vec(),
+                InstitutionType::Treasury,
+                3,
+                signatories(FRA),
+                vec![tier(4)],
+            ),
+            Error::<Test>::InvalidTierApprovals
+        );
+        // No tier may go below the minimum signature threshold
+        assert_noop!(
+            InterCountryPayments::register_government(
+                RuntimeOrigin::root(),
+                b"FRA".to_vec(),
+                b"Treasury".to_vec(),
+                InstitutionType::Treasury,
+                3,
+                signatories(FRA),
+                vec![tier(1)],
+            ),
+            Error::<Test>::InvalidTierApprovals
+        );
+
+        let fra = register_with(b"FRA", FRA, vec![tier(2)]);
+        let deu = register(b"DEU", DEU);
+        let sign = |payment_id| {
+            for approver in [FRA + 1, FRA + 2] {
+                assert_ok!(InterCountryPayments::sign_payment(
+                    RuntimeOrigin::signed(account(approver)),
+                    payment_id,
+                    Vec::new(),
+                ));
+            }
+        };
+
+        let small = initiate(&fra, FRA, &deu, 100);
+        sign(small);
+        assert_eq!(status(small), PaymentStatus::Authorized);
+
+        let large = initiate(&fra, FRA, &deu, 101);
+        sign(large);
+        assert_eq!(status(large), PaymentStatus::Pending);
+    });
+}
+
+#[test]
+fn signatures_count_only_from_current_signatories() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        let sign = |payment_id: u64, signer: u8| {
+            InterCountryPayments::sign_payment(RuntimeOrigin::signed(account(signer)), payment_id, Vec::new())
+        };
+
+        let pending = initiate(&fra, FRA, &deu, 100);
+        assert_ok!(sign(pending, FRA + 1));
+        assert_ok!(sign(pending, FRA + 2));
+
+        // A payment that failed to settle while every approver had signed it
+        let failed = initiate(&fra, FRA, &deu, 100);
+        authorize(failed, FRA);
+        act(&deu, DEU, GovernmentAction::SetCounterpartyRule {
+            counterparty: fra.clone(),
+            rule: Some(CounterpartyRule::Deny),
+        });
+        execute(failed, FRA);
+        assert_eq!(status(failed), PaymentStatus::Failed);
+        act(&deu, DEU, GovernmentAction::SetCounterpartyRule { counterparty: fra.clone(), rule: None });
+
+        let mut replaced = signatories(FRA);
+        replaced[1].account = account(FRA + 6);
+        act(&fra, FRA, GovernmentAction::UpdateSignatories { signatories: replaced, signature_threshold: 3 });
+
+        // The removed approver can no longer sign and its signature no longer counts
+        assert_noop!(sign(pending, FRA + 1), Error::<Test>::NotAuthorizedSigner);
+        assert_ok!(sign(pending, FRA + 3));
+        assert_eq!(status(pending), PaymentStatus::Pending);
+        assert_ok!(sign(pending, FRA + 6));
+        assert_eq!(status(pending), PaymentStatus::Authorized);
+
+        // A retried payment needs signatures of current approvers again
+        assert_ok!(InterCountryPayments::retry_payment(RuntimeOrigin::signed(account(FRA + 4)), failed));
+        assert_eq!(status(failed), PaymentStatus::Pending);
+        assert_eq!(InterCountryPayments::payment_info(failed).unwrap().signatures.len(), 2);
+        assert_ok!(sign(failed, FRA + 6));
+        execute(failed, FRA);
+        assert_eq!(status(failed), PaymentStatus::Completed);
+    });
+}
+
+#[test]
+fn separation_of_duties_is_enforced() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        let payment_id = initiate(&fra, FRA, &deu, 1_000);
+
+        assert_noop!(
+            InterCountryPayments::sign_payment(RuntimeOrigin::signed(account(FRA)), payment_id, Vec::new()),
+            Error::<Test>::InitiatorCannotApprove
+        );
+        assert_noop!(
+            InterCountryPayments::sign_payment(RuntimeOrigin::signed(account(FRA + 4)), payment_id, Vec::new()),
+            Error::<Test>::MissingRole
+        );
+        assert_noop!(
+            InterCountryPayments::sign_payment(RuntimeOrigin::signed(account(99)), payment_id, Vec::new()),
+            Error::<Test>::NotAuthorizedSigner
+        );
+
+        authorize(payment_id, FRA);
+        assert_noop!(
+            InterCountryPayments::execute_payment(RuntimeOrigin::signed(account(FRA + 1)), payment_id),
+            Error::<Test>::MissingRole
+        );
+    });
+}
+
+#[test]
+fn threshold_must_be_reachable_without_the_initiator() {
+    new_test_ext().execute_with(|| {
+        // Three approvers, one of which also initiates: only two can approve its payments
+        let mut set = signatories(FRA);
+        set[0].roles.push(SignatoryRole::Approver);
+        set[1].roles = vec![SignatoryRole::Auditor];
+        assert_noop!(
+            register_call(b"FRA", InstitutionType::Treasury, set, 3),
+            Error::<Test>::SignatureThresholdTooHigh
+        );
+
+        // With a fourth approver every payment can still collect three approvals
+        let mut set = signatories(FRA);
+        set[0].roles.push(SignatoryRole::Approver);
+        assert_ok!(register_call(b"FRA", InstitutionType::Treasury, set, 3));
+        let fra = InterCountryPayments::country_to_account(b"FRA".to_vec(), InstitutionType::Treasury).unwrap();
+        assert_ok!(Balances::mint_into(&fra, INITIAL_BALANCE));
+        let deu = register(b"DEU", DEU);
+
+        let payment_id = initiate(&fra, FRA, &deu, 1_000);
+        authorize(payment_id, FRA);
+        assert_eq!(status(payment_id), PaymentStatus::Authorized);
+    });
+}
+
+#[test]
+fn government_accounts_are_keyless_and_derived() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        assert_eq!(fra, InterCountryPayments::government_account_id(b"FRA", &InstitutionType::Treasury, 0));
+
+        assert_noop!(initiate_call(&fra, 99, &deu, 100), Error::<Test>::NotAuthorizedSigner);
+        assert_noop!(initiate_call(&fra, FRA + 1, &deu, 100), Error::<Test>::MissingRole);
+    });
+}
+
+#[test]
+fn keyed_version_1_government_rotates_to_a_keyless_account() {
+    new_test_ext().execute_with(|| {
+        let keyed = account(50);
+        let old_government = migrations::v1::GovernmentInfo {
+            country_code: b"FRA".to_vec(),
+            government_name: b"Treasury".to_vec(),
+            institution_type: InstitutionType::Treasury,
+            status: GovernmentStatus::Active,
+            signature_threshold: 2,
+            authorized_signatories: vec![account(51), account(52), account(53)],
+            registered_at: 0,
+        };
+        unhashed::put(&GovernmentRegistry::<Test>::hashed_key_for(&keyed), &old_government);
+        assert_ok!(Balances::mint_into(&keyed, 1_000));
+        StorageVersion::new(1).put::<InterCountryPayments>();
+        migrations::v2::NormaliseCountryCodes::<Test>::on_runtime_upgrade();
+
+        assert_ok!(InterCountryPayments::propose_government_action(
+            RuntimeOrigin::signed(account(51)),
+            keyed.clone(),
+            GovernmentAction::RotateAccount,
+        ));
+        assert_ok!(InterCountryPayments::approve_government_action(RuntimeOrigin::signed(account(52)), keyed.clone(), 0));
+        assert_ok!(InterCountryPayments::enact_account_rotation(RuntimeOrigin::signed(account(1)), keyed.clone(), 0));
+
+        let keyless = InterCountryPayments::government_account_id(b"FRA", &InstitutionType::Treasury, 1);
+        assert!(InterCountryPayments::government_info(&keyed).is_none());
+        assert_eq!(InterCountryPayments::government_info(&keyless).unwrap().account_generation, 1);
+        assert_eq!(Balances::balance(&keyless), 1_000);
+        assert_eq!(Balances::balance(&keyed), 0);
+    });
+}
+
+#[test]
+fn rotation_moves_state_and_balances() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        register(b"JPN", JPN);
+
+        assert_ok!(Balances::hold(&HoldReason::DisputeBond.into(), &fra, 50));
+        let settled = initiate(&fra, FRA, &deu, 100);
+        authorize(settled, FRA);
+        execute(settled, FRA);
+        assert_ok!(InterCountryPayments::initiate_payment(
+            RuntimeOrigin::signed(account(FRA)),
+            fra.clone(),
+            deu.clone(),
+            1_000,
+            trade(),
+            b"INV-1".to_vec(),
+            false,
+        ));
+        let payment_id = settled + 1;
+        act(&deu, DEU, GovernmentAction::SetCounterpartyRule {
+            counterparty: fra.clone(),
+            rule: Some(CounterpartyRule::Allow),
+        });
+        assert_ok!(InterCountryPayments::update_government_info(RuntimeOrigin::root(), fra.clone(), rename(b"Tresor")));
+        let call = add_country_code(b"ESP");
+        let motion_hash = BlakeTwo256::hash_of(&call);
+        assert_ok!(InterCountryPayments::propose_motion(
+            RuntimeOrigin::signed(account(FRA + 1)),
+            fra.clone(),
+            Box::new(call),
+        ));
+        assert_ok!(InterCountryPayments::propose_government_action(
+            RuntimeOrigin::signed(account(FRA + 1)),
+            fra.clone(),
+            GovernmentAction::SetCounterpartyMode { mode: CounterpartyMode::AllowListedOnly },
+        ));
+
+        // The rotation must be given every counterparty entry it moves
+        act(&fra, FRA, GovernmentAction::RotateAccount);
+        assert_eq!(InterCountryPayments::counterparty_entries(&fra), 2);
+        assert_noop!(
+            InterCountryPayments::enact_account_rotation(RuntimeOrigin::signed(account(1)), fra.clone(), 1),
+            Error::<Test>::TooManyCounterpartyEntries
+        );
+        assert_ok!(InterCountryPayments::enact_account_rotation(RuntimeOrigin::signed(account(1)), fra.clone(), 2));
+
+        let new = InterCountryPayments::government_account_id(b"FRA", &InstitutionType::Treasury, 1);
+        assert!(InterCountryPayments::government_info(&fra).is_none());
+        assert_eq!(InterCountryPayments::government_info(&new).unwrap().account_generation, 1);
+        assert_eq!(
+            InterCountryPayments::country_to_account(b"FRA".to_vec(), InstitutionType::Treasury),
+            Some(new.clone())
+        );
+        assert!(InterCountryPayments::signatory_of(account(FRA + 1), &new).is_some());
+        assert!(InterCountryPayments::signatory_of(account(FRA + 1), &fra).is_none());
+
+        assert_eq!(Balances::total_balance(&fra), 0);
+        assert_eq!(Balances::balance(&new), INITIAL_BALANCE - 50 - 100 - 1_000 - 2 * FEE);
+        assert_eq!(held(HoldReason::DisputeBond, &new), 50);
+
+        // Only the payment in flight is re-keyed; the history and totals move as a whole
+        assert_eq!(InterCountryPayments::payment_info(payment_id).unwrap().from_government, new);
+        assert_eq!(InterCountryPayments::payment_info(settled).unwrap().from_government, fra);
+        assert_eq!(InterCountryPayments::payment_id_by_reference(&new, b"INV-1".to_vec()), Some(payment_id));
+        assert_eq!(InterCountryPayments::unsettled_payments(&new).into_inner(), vec![payment_id]);
+        assert_eq!(InterCountryPayments::get_government_stats(&new), Some((2, 100, 0)));
+
+        assert_eq!(InterCountryPayments::counterparty_rule(&deu, &new), Some(CounterpartyRule::Allow));
+        assert_eq!(InterCountryPayments::counterparty_rule(&deu, &fra), None);
+        assert!(InterCountryPayments::counterparty_index(&new, &deu).is_some());
+        assert_eq!(InterCountryPayments::government_profile_history(&new).unwrap().len(), 1);
+
+        // Votes cast under the old account lapse and the new account votes afresh
+        assert_ok!(InterCountryPayments::vote_motion(RuntimeOrigin::signed(account(FRA + 2)), new.clone(), motion_hash, true));
+        assert_eq!(InterCountryPayments::motion_votes(motion_hash).unwrap().ayes, vec![fra.clone(), new.clone()]);
+
+        // Proposals left under the old account can be cleared in steps
+        assert_noop!(
+            InterCountryPayments::clear_former_account_state(RuntimeOrigin::signed(account(1)), new.clone(), 10),
+            Error::<Test>::AccountStillRegistered
+        );
+        assert_ok!(InterCountryPayments::clear_former_account_state(RuntimeOrigin::signed(account(1)), fra.clone(), 1));
+        assert!(InterCountryPayments::government_proposal(&fra, 0).is_none());
+        assert!(InterCountryPayments::government_motion(&fra, motion_hash).is_some());
+        assert_ok!(InterCountryPayments::clear_former_account_state(RuntimeOrigin::signed(account(1)), fra.clone(), 10));
+        assert!(InterCountryPayments::government_motion(&fra, motion_hash).is_none());
+        System::assert_last_event(
+            Event::FormerAccountStateCleared { account: fra.clone(), removed: 1, complete: true }.into(),
+        );
+
+        // Payments in flight settle from the new account
+        authorize(payment_id, FRA);
+        execute(payment_id, FRA);
+        assert_eq!(Balances::balance(&deu), INITIAL_BALANCE + 1_100);
+        assert!(InterCountryPayments::unsettled_payments(&deu).is_empty());
+    });
+}
+
+#[test]
+fn rotation_waits_for_its_delay_and_can_be_vetoed() {
+    new_test_ext().execute_with(|| {
+        RotationDelay::set(10);
+        let fra = register(b"FRA", FRA);
+        let new = InterCountryPayments::government_account_id(b"FRA", &InstitutionType::Treasury, 1);
+
+        act(&fra, FRA, GovernmentAction::RotateAccount);
+        assert_eq!(InterCountryPayments::pending_rotation(&fra).unwrap().enact_at, 11);
+        assert_noop!(
+            InterCountryPayments::enact_account_rotation(RuntimeOrigin::signed(account(1)), fra.clone(), 0),
+            Error::<Test>::RotationDelayNotElapsed
+        );
+        assert_ok!(InterCountryPayments::veto_account_rotation(RuntimeOrigin::root(), fra.clone()));
+        assert!(InterCountryPayments::pending_rotation(&fra).is_none());
+
+        act(&fra, FRA, GovernmentAction::RotateAccount);
+        System::set_block_number(11);
+        assert_ok!(InterCountryPayments::enact_account_rotation(RuntimeOrigin::signed(account(1)), fra.clone(), 0));
+        assert!(InterCountryPayments::government_info(&fra).is_none());
+        assert_eq!(Balances::balance(&new), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn several_institutions_per_country() {
+    new_test_ext().execute_with(|| {
+        register(b"FRA", FRA);
+        assert_ok!(register_call(b"FRA", InstitutionType::CentralBank, signatories(50), 3));
+        assert_noop!(
+            register_call(b"FRA", InstitutionType::Treasury, signatories(60), 3),
+            Error::<Test>::InstitutionAlreadyRegistered
+        );
+        assert_eq!(InterCountryPayments::get_country_institutions(b"FRA").len(), 2);
+    });
+}
+
+#[test]
+fn country_codes_are_validated() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            register_call(b"fra", InstitutionType::Treasury, signatories(FRA), 3),
+            Error::<Test>::InvalidCountryCode
+        );
+        assert_noop!(
+            register_call(b"ESP", InstitutionType::Treasury, signatories(FRA), 3),
+            Error::<Test>::UnknownCountryCode
+        );
+        assert_noop!(
+            InterCountryPayments::add_country_code(RuntimeOrigin::signed(account(1)), vec![b"ESP".to_vec()]),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            InterCountryPayments::add_country_code(RuntimeOrigin::root(), vec![b"ESP".to_vec(), b"pt".to_vec()]),
+            Error::<Test>::InvalidCountryCode
+        );
+
+        assert_ok!(InterCountryPayments::add_country_code(RuntimeOrigin::root(), vec![b"ESP".to_vec(), b"PRT".to_vec()]));
+        assert!(InterCountryPayments::is_listed_country_code(b"PRT".to_vec()).is_some());
+        assert_ok!(register_call(b"ESP", InstitutionType::Treasury, signatories(FRA), 3));
+        assert_eq!(InterCountryPayments::normalise_country_code(b" esp\n"), b"ESP".to_vec());
+    });
+}
+
+#[test]
+fn v2_migration_upgrades_version_1_governments_and_payments() {
+    new_test_ext().execute_with(|| {
+        let government = account(50);
+        let old_signatories = vec![account(51), account(52), account(53)];
+        let old_government = migrations::v1::GovernmentInfo {
+            country_code: b" fra".to_vec(),
+            government_name: b"Treasury".to_vec(),
+            institution_type: InstitutionType::Treasury,
+            status: GovernmentStatus::Active,
+            signature_threshold: 2,
+            authorized_signatories: old_signatories.clone(),
+            registered_at: 0,
+        };
+        unhashed::put(&GovernmentRegistry::<Test>::hashed_key_for(&government), &old_government);
+        let old_payment = migrations::v1::InterCountryPayment {
+            payment_id: 0,
+            from_government: government.clone(),
+            to_government: account(60),
+            amount: 100,
+            purpose: PaymentPurpose::DevelopmentAid {
+                program_id: b"P-1".to_vec(),
+                beneficiary_country: b"deu ".to_vec(),
+            },
+            reference: Vec::new(),
+            required_signatures: old_signatories.clone(),
+            signatures: Vec::new(),
+            timestamp: 0,
+            status: PaymentStatus::Pending,
+        };
+        unhashed::put(&PaymentRegistry::<Test>::hashed_key_for(0), &old_payment);
+        ContributionsReceived::<Test>::insert(account(70), (b"fra".to_vec(), ContributionType::MembershipFees), 10);
+        ContributionsReceived::<Test>::insert(account(70), (b"FRA".to_vec(), ContributionType::MembershipFees), 5);
+        let _ = ValidCountryCodes::<Test>::clear(u32::MAX, None);
+        StorageVersion::new(1).put::<InterCountryPayments>();
+
+        migrations::v2::NormaliseCountryCodes::<Test>::on_runtime_upgrade();
+
+        assert_eq!(InterCountryPayments::on_chain_storage_version(), 2);
+        let gov = InterCountryPayments::government_info(&government).unwrap();
+        assert_eq!(gov.country_code, b"FRA".to_vec());
+        assert_eq!(gov.signature_threshold, 2);
+        assert_eq!(gov.account_generation, 0);
+        assert!(gov
+            .authorized_signatories
+            .iter()
+            .all(|s| s.roles == migrations::v1::SIGNATORY_ROLES.to_vec()));
+        assert_eq!(
+            InterCountryPayments::country_to_account(b"FRA".to_vec(), InstitutionType::Treasury),
+            Some(government.clone())
+        );
+        assert_eq!(InterCountryPayments::active_government_count(), 1);
+        assert_eq!(ValidCountryCodes::<Test>::iter_keys().collect::<Vec<_>>(), vec![b"FRA".to_vec()]);
+
+        let payment = InterCountryPayments::payment_info(0).unwrap();
+        assert_eq!(payment.initiator, government);
+        assert_eq!(payment.approval_threshold, 2);
+        assert_eq!(payment.required_signatures, old_signatories);
+        assert_eq!(
+            payment.purpose,
+            PaymentPurpose::DevelopmentAid { program_id: b"P-1".to_vec(), beneficiary_country: b"DEU".to_vec() }
+        );
+
+        assert_eq!(
+            InterCountryPayments::contributions_received(account(70), (b"FRA".to_vec(), ContributionType::MembershipFees)),
+            15
+        );
+        assert_eq!(ContributionsReceived::<Test>::iter().count(), 1);
+    });
+}
+
+#[test]
+fn v2_migration_skips_other_versions() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        StorageVersion::new(2).put::<InterCountryPayments>();
+
+        migrations::v2::NormaliseCountryCodes::<Test>::on_runtime_upgrade();
+
+        assert_eq!(InterCountryPayments::on_chain_storage_version(), 2);
+        assert!(InterCountryPayments::government_info(&fra).is_some());
+    });
+}
+
+#[test]
+fn payment_to_country_resolves_recipient() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        let to_country = |country_code: &[u8], institution_type| {
+            InterCountryPayments::initiate_payment_to_country(
+                RuntimeOrigin::signed(account(FRA)),
+                fra.clone(),
+                country_code.to_vec(),
+                institution_type,
+                100,
+                trade(),
+                Vec::new(),
+                false,
+            )
+        };
+
+        assert_noop!(to_country(b"DEU", InstitutionType::CentralBank), Error::<Test>::CountryNotRegistered);
+        let payment_id = InterCountryPayments::next_payment_id();
+        assert_ok!(to_country(b"DEU", InstitutionType::Treasury));
+
+        let payment = InterCountryPayments::payment_info(payment_id).unwrap();
+        assert_eq!(payment.to_government, deu);
+        assert_eq!(payment.recipient_country, Some((b"DEU".to_vec(), InstitutionType::Treasury)));
+    });
+}
+
+#[test]
+fn signatory_sets_are_validated() {
+    new_test_ext().execute_with(|| {
+        let government = InterCountryPayments::government_account_id(b"FRA", &InstitutionType::Treasury, 0);
+        let register_set = |set, threshold| register_call(b"FRA", InstitutionType::Treasury, set, threshold);
+
+        let mut set = signatories(FRA);
+        set[4].roles.clear();
+        assert_noop!(register_set(set, 3), Error::<Test>::SignatoryWithoutRoles);
+
+        let mut set = signatories(FRA);
+        set[4].account = account(FRA + 1);
+        assert_noop!(register_set(set, 3), Error::<Test>::DuplicateSignatory);
+
+        let mut set = signatories(FRA);
+        set[4].account = government;
+        assert_noop!(register_set(set, 3), Error::<Test>::GovernmentAccountAsSignatory);
+
+        let mut set = signatories(FRA);
+        set.truncate(4);
+        assert_noop!(register_set(set, 3), Error::<Test>::TooFewSignatories);
+
+        assert_noop!(register_set(signatories(FRA), 1), Error::<Test>::ThresholdBelowMinimum);
+        assert_noop!(register_set(signatories(FRA), 4), Error::<Test>::SignatureThresholdTooHigh);
+    });
+}
+
+#[test]
+fn profile_updates_keep_history() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+
+        let mut update = rename(b"Tresor public");
+        update.bic = Some(Some(b"BDF".to_vec()));
+        assert_noop!(
+            InterCountryPayments::update_government_info(RuntimeOrigin::root(), fra.clone(), update.clone()),
+            Error::<Test>::InvalidBic
+        );
+
+        update.bic = Some(Some(b"BDFEFRPP".to_vec()));
+        update.institution_type = Some(InstitutionType::CentralBank);
+        assert_noop!(
+            InterCountryPayments::update_government_info(RuntimeOrigin::root(), fra.clone(), update.clone()),
+            Error::<Test>::InstitutionTypeImmutable
+        );
+
+        update.institution_type = Some(InstitutionType::Treasury);
+        assert_ok!(InterCountryPayments::update_government_info(RuntimeOrigin::root(), fra.clone(), update));
+        act(&fra, FRA, GovernmentAction::UpdateProfile { update: rename(b"Direction du Tresor") });
+
+        let gov = InterCountryPayments::government_info(&fra).unwrap();
+        assert_eq!(gov.government_name, b"Direction du Tresor".to_vec());
+        assert_eq!(gov.bic, Some(b"BDFEFRPP".to_vec()));
+        assert_eq!(gov.institution_type, InstitutionType::Treasury);
+        let history = InterCountryPayments::government_profile_history(&fra).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].government_name, b"Treasury".to_vec());
+        assert_eq!(history[1].government_name, b"Tresor public".to_vec());
+    });
+}
+
+#[test]
+fn deregistration_settles_and_never_reuses_the_account() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+
+        // A payment that failed while the recipient denied the sender
+        let failed = initiate(&fra, FRA, &deu, 100);
+        authorize(failed, FRA);
+        act(&deu, DEU, GovernmentAction::SetCounterpartyRule {
+            counterparty: fra.clone(),
+            rule: Some(CounterpartyRule::Deny),
+        });
+        execute(failed, FRA);
+        assert_eq!(status(failed), PaymentStatus::Failed);
+        act(&deu, DEU, GovernmentAction::SetCounterpartyRule {
+            counterparty: fra.clone(),
+            rule: Some(CounterpartyRule::Allow),
+        });
+        let payment_id = initiate(&fra, FRA, &deu, 1_000);
+        assert_ok!(InterCountryPayments::propose_government_action(
+            RuntimeOrigin::signed(account(FRA + 1)),
+            fra.clone(),
+            GovernmentAction::SetCounterpartyMode { mode: CounterpartyMode::AllowListedOnly },
+        ));
+
+        assert_noop!(
+            InterCountryPayments::deregister_government(RuntimeOrigin::root(), fra.clone(), None, 1),
+            Error::<Test>::GovernmentNotRevoked
+        );
+        assert_ok!(InterCountryPayments::update_government_status(
+            RuntimeOrigin::root(),
+            fra.clone(),
+            GovernmentStatus::Revoked,
+        ));
+
+        // An application left open from before status changes were refused for candidates
+        assert_ok!(Balances::hold(&HoldReason::EscrowHold.into(), &account(APPLICANT), DEPOSIT));
+        MembershipApplications::<Test>::insert(
+            &fra,
+            MembershipApplication {
+                depositor: account(APPLICANT),
+                deposit: DEPOSIT,
+                ayes: vec![deu.clone()],
+                nays: Vec::new(),
+                submitted_at: 1,
+                end: 11,
+            },
+        );
+        GovernmentMembershipVotes::<Test>::insert(&deu, &fra, ());
+
+        assert_noop!(
+            InterCountryPayments::deregister_government(RuntimeOrigin::root(), fra.clone(), None, 0),
+            Error::<Test>::TooManyCounterpartyEntries
+        );
+        assert_ok!(InterCountryPayments::deregister_government(RuntimeOrigin::root(), fra.clone(), None, 1));
+
+        assert_eq!(status(payment_id), PaymentStatus::Cancelled);
+        assert_eq!(status(failed), PaymentStatus::Cancelled);
+        assert!(InterCountryPayments::unsettled_payments(&deu).is_empty());
+        assert!(InterCountryPayments::government_info(&fra).is_none());
+        assert_eq!(Balances::balance(&TreasuryAccount::get()), INITIAL_BALANCE);
+        let summary = InterCountryPayments::deregistered_government(&fra).unwrap();
+        assert_eq!(summary.successor, TreasuryAccount::get());
+        assert_eq!(summary.returned_balance, INITIAL_BALANCE);
+        assert_eq!(summary.payment_count, 2);
+        assert_eq!(InterCountryPayments::counterparty_rule(&deu, &fra), None);
+        assert!(InterCountryPayments::counterparty_index(&fra, &deu).is_none());
+
+        // The open application is withdrawn and its deposit returned
+        assert!(InterCountryPayments::membership_application(&fra).is_none());
+        assert!(InterCountryPayments::government_membership_vote(&deu, &fra).is_none());
+        assert_eq!(held(HoldReason::EscrowHold, &account(APPLICANT)), 0);
+
+        // Proposals of the former account are cleared separately
+        assert!(InterCountryPayments::government_proposal(&fra, 0).is_some());
+        assert_ok!(InterCountryPayments::clear_former_account_state(RuntimeOrigin::signed(account(1)), fra.clone(), 10));
+        assert!(InterCountryPayments::government_proposal(&fra, 0).is_none());
+        assert_eq!(InterCountryPayments::next_government_proposal_id(&fra), 0);
+
+        assert_eq!(InterCountryPayments::next_account_generation(b"FRA".to_vec(), InstitutionType::Treasury), 1);
+        let again = register(b"FRA", FRA);
+        assert_ne!(again, fra);
+        assert_eq!(again, InterCountryPayments::government_account_id(b"FRA", &InstitutionType::Treasury, 1));
+    });
+}
+
+#[test]
+fn status_changes_visit_only_unsettled_payments() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        let settled = initiate(&fra, FRA, &deu, 100);
+        authorize(settled, FRA);
+        execute(settled, FRA);
+        let pending = initiate(&fra, FRA, &deu, 100);
+        assert_eq!(InterCountryPayments::unsettled_payments(&fra).into_inner(), vec![pending]);
+
+        let call = crate::Call::<Test>::update_government_status {
+            government: fra.clone(),
+            new_status: GovernmentStatus::Suspended,
+        };
+        assert_eq!(call.get_dispatch_info().weight, InterCountryPayments::payment_policy_weight());
+        assert_ok!(InterCountryPayments::update_government_status(
+            RuntimeOrigin::root(),
+            fra.clone(),
+            GovernmentStatus::Suspended,
+        ));
+
+        assert_eq!(status(settled), PaymentStatus::Completed);
+        assert_eq!(status(pending), PaymentStatus::Frozen);
+    });
+}
+
+#[test]
+fn status_changes_freeze_resume_and_cancel_payments() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        let payment_id = initiate(&fra, FRA, &deu, 1_000);
+        authorize(payment_id, FRA);
+
+        assert_ok!(InterCountryPayments::update_government_status(
+            RuntimeOrigin::root(),
+            deu.clone(),
+            GovernmentStatus::Suspended,
+        ));
+        assert_eq!(status(payment_id), PaymentStatus::Frozen);
+        assert_eq!(InterCountryPayments::frozen_payment(payment_id), Some(PaymentStatus::Authorized));
+        assert_noop!(
+            InterCountryPayments::execute_payment(RuntimeOrigin::signed(account(FRA + 4)), payment_id),
+            Error::<Test>::PaymentNotPending
+        );
+
+        assert_ok!(InterCountryPayments::update_government_status(
+            RuntimeOrigin::root(),
+            deu,
+            GovernmentStatus::Active,
+        ));
+        assert_eq!(status(payment_id), PaymentStatus::Authorized);
+
+        assert_ok!(InterCountryPayments::update_government_status(
+            RuntimeOrigin::root(),
+            fra.clone(),
+            GovernmentStatus::Revoked,
+        ));
+        assert_eq!(status(payment_id), PaymentStatus::Cancelled);
+        assert_eq!(Balances::balance(&fra), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn auto_execute_settles_on_the_authorizing_signature() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        let payment_id = InterCountryPayments::next_payment_id();
+        assert_ok!(InterCountryPayments::initiate_payment(
+            RuntimeOrigin::signed(account(FRA)),
+            fra,
+            deu.clone(),
+            1_000,
+            trade(),
+            Vec::new(),
+            true,
+        ));
+
+        authorize(payment_id, FRA);
+
+        assert_eq!(status(payment_id), PaymentStatus::Completed);
+        assert_eq!(Balances::balance(&deu), INITIAL_BALANCE + 1_000);
+    });
+}
+
+#[test]
+fn failed_settlement_refunds_and_can_be_retried() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        let payment_id = initiate(&fra, FRA, &deu, 1_000);
+        authorize(payment_id, FRA);
+
+        act(&deu, DEU, GovernmentAction::SetCounterpartyRule {
+            counterparty: fra.clone(),
+            rule: Some(CounterpartyRule::Deny),
+        });
+        execute(payment_id, FRA);
+
+        let payment = InterCountryPayments::payment_info(payment_id).unwrap();
+        assert_eq!(payment.status, PaymentStatus::Failed);
+        assert_eq!(payment.failure_reason, Some(PaymentFailureReason::CounterpartyNotPermitted));
+        assert_eq!(Balances::balance(&fra), INITIAL_BALANCE);
+        assert!(!System::account_exists(&escrow(payment_id)));
+
+        // Retrying while the rule stands fails again and refunds again
+        assert_ok!(InterCountryPayments::retry_payment(RuntimeOrigin::signed(account(FRA + 4)), payment_id));
+        assert_eq!(status(payment_id), PaymentStatus::Failed);
+        assert_eq!(Balances::balance(&fra), INITIAL_BALANCE);
+        assert!(!System::account_exists(&escrow(payment_id)));
+
+        act(&deu, DEU, GovernmentAction::SetCounterpartyRule { counterparty: fra.clone(), rule: None });
+        assert_noop!(
+            InterCountryPayments::retry_payment(RuntimeOrigin::signed(account(FRA + 1)), payment_id),
+            Error::<Test>::MissingRole
+        );
+        assert_ok!(InterCountryPayments::retry_payment(RuntimeOrigin::signed(account(FRA + 4)), payment_id));
+
+        let payment = InterCountryPayments::payment_info(payment_id).unwrap();
+        assert_eq!(payment.status, PaymentStatus::Completed);
+        assert_eq!(payment.failure_reason, None);
+        assert_eq!(Balances::balance(&fra), INITIAL_BALANCE - 1_000 - FEE);
+        assert_eq!(Balances::balance(&deu), INITIAL_BALANCE + 1_000);
+        assert!(!System::account_exists(&escrow(payment_id)));
+    });
+}
+
+#[test]
+fn unsettled_payments_are_indexed_and_bounded() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        let payments: Vec<_> = (0..8).map(|_| initiate(&fra, FRA, &deu, 10)).collect();
+        assert_eq!(InterCountryPayments::unsettled_payments(&deu).into_inner(), payments);
+        assert_noop!(initiate_call(&fra, FRA, &deu, 10), Error::<Test>::TooManyUnsettledPayments);
+
+        // Settled and cancelled payments leave the index of both parties
+        authorize(payments[0], FRA);
+        execute(payments[0], FRA);
+        assert_ok!(InterCountryPayments::cancel_payment(RuntimeOrigin::signed(account(FRA)), payments[1], b"void".to_vec()));
+        assert_eq!(InterCountryPayments::unsettled_payments(&fra).into_inner(), payments[2..].to_vec());
+        assert_eq!(InterCountryPayments::unsettled_payments(&deu).into_inner(), payments[2..].to_vec());
+        assert_eq!(InterCountryPayments::payment_totals(&deu), (0, 10));
+
+        // Failed payments stay in flight until they are retried or cancelled
+        authorize(payments[2], FRA);
+        act(&deu, DEU, GovernmentAction::SetCounterpartyRule {
+            counterparty: fra.clone(),
+            rule: Some(CounterpartyRule::Deny),
+        });
+        execute(payments[2], FRA);
+        assert_eq!(status(payments[2]), PaymentStatus::Failed);
+        assert!(InterCountryPayments::unsettled_payments(&fra).contains(&payments[2]));
+        let balance = Balances::balance(&fra);
+        assert_ok!(InterCountryPayments::cancel_payment(RuntimeOrigin::signed(account(FRA)), payments[2], b"void".to_vec()));
+        assert_eq!(status(payments[2]), PaymentStatus::Cancelled);
+        assert_eq!(Balances::balance(&fra), balance);
+        assert!(!InterCountryPayments::unsettled_payments(&fra).contains(&payments[2]));
+    });
+}
+
+#[test]
+fn signatory_quorum_cancels_authorized_payment() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        let payment_id = initiate(&fra, FRA, &deu, 1_000);
+        authorize(payment_id, FRA);
+
+        act(&fra, FRA, GovernmentAction::CancelPayment { payment_id, reason: b"fraud".to_vec() });
+
+        assert_eq!(status(payment_id), PaymentStatus::Cancelled);
+        assert_eq!(Balances::balance(&fra), INITIAL_BALANCE);
+        let cancellation = InterCountryPayments::payment_cancellation(payment_id).unwrap();
+        assert_eq!(cancellation.reason, b"fraud".to_vec());
+        assert_eq!(cancellation.signers, vec![account(FRA + 1), account(FRA + 2), account(FRA + 3)]);
+    });
+}
+
+#[test]
+fn each_payment_has_its_own_escrow() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+
+        let first = initiate(&fra, FRA, &deu, 100);
+        let second = initiate(&fra, FRA, &deu, 200);
+        assert_ne!(escrow(first), escrow(second));
+        assert_eq!(held(HoldReason::PaymentInFlight, &escrow(first)), 100 + FEE);
+        assert_eq!(held(HoldReason::PaymentInFlight, &escrow(second)), 200 + FEE);
+        assert_eq!(InterCountryPayments::check_escrow_invariant(), Ok(300 + 2 * FEE));
+    });
+}
+
+#[test]
+fn v3_migration_moves_reserves_to_escrow() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        PaymentRegistry::<Test>::insert(0, stored_payment(0, &fra, &deu, b""));
+        let mut completed = stored_payment(1, &fra, &deu, b"");
+        completed.status = PaymentStatus::Completed;
+        PaymentRegistry::<Test>::insert(1, completed);
+        assert_ok!(<Balances as ReservableCurrency<AccountId>>::reserve(&fra, 1_000 + FEE));
+        StorageVersion::new(2).put::<InterCountryPayments>();
+
+        migrations::v3::MoveReservesToEscrow::<Test, Balances>::on_runtime_upgrade();
+
+        assert_eq!(InterCountryPayments::on_chain_storage_version(), 3);
+        assert_eq!(<Balances as ReservableCurrency<AccountId>>::reserved_balance(&fra), 0);
+        assert_eq!(Balances::balance(&fra), INITIAL_BALANCE - 1_000 - FEE);
+        assert_eq!(Balances::balance(&escrow(0)), 1_000 + FEE);
+        assert!(!System::account_exists(&escrow(1)));
+    });
+}
+
+#[test]
+fn references_are_unique_per_sender() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        let with_reference = |from: &AccountId, base: u8, to: &AccountId| {
+            InterCountryPayments::initiate_payment(
+                RuntimeOrigin::signed(account(base)),
+                from.clone(),
+                to.clone(),
+                100,
+                trade(),
+                b"INV-1".to_vec(),
+                false,
+            )
+        };
+
+        assert_ok!(with_reference(&fra, FRA, &deu));
+        assert_noop!(with_reference(&fra, FRA, &deu), Error::<Test>::DuplicateReference);
+        assert_ok!(with_reference(&deu, DEU, &fra));
+
+        assert_eq!(InterCountryPayments::payment_by_reference(&fra, b"INV-1").unwrap().payment_id, 0);
+        assert_eq!(InterCountryPayments::payment_by_reference(&deu, b"INV-1").unwrap().payment_id, 1);
+    });
+}
+
+#[test]
+fn v5_migration_indexes_first_use_of_each_reference() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        PaymentRegistry::<Test>::insert(0, stored_payment(0, &fra, &deu, b"INV-1"));
+        PaymentRegistry::<Test>::insert(1, stored_payment(1, &fra, &deu, b"INV-1"));
+        PaymentRegistry::<Test>::insert(2, stored_payment(2, &fra, &deu, b""));
+        StorageVersion::new(4).put::<InterCountryPayments>();
+
+        migrations::v5::BuildReferenceIndex::<Test>::on_runtime_upgrade();
+
+        assert_eq!(InterCountryPayments::on_chain_storage_version(), 5);
+        assert_eq!(InterCountryPayments::payment_id_by_reference(&fra, b"INV-1".to_vec()), Some(0));
+        assert_eq!(PaymentReferences::<Test>::iter().count(), 1);
+    });
+}
+
+#[test]
+fn check_government_caller_admits_governments_and_allowlist() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let call = RuntimeCall::InterCountryPayments(crate::Call::execute_payment { payment_id: 0 });
+        let info = call.get_dispatch_info();
+        let check = CheckGovernmentCaller::<Test>::new();
+
+        assert_ok!(check.validate(&account(FRA + 4), &call, &info, 0));
+        assert_ok!(check.validate(&fra, &call, &info, 0));
+        assert_eq!(check.validate(&account(99), &call, &info, 0), Err(InvalidTransaction::BadSigner.into()));
+
+        assert_ok!(InterCountryPayments::add_allowed_caller(RuntimeOrigin::root(), account(99)));
+        assert_ok!(check.validate(&account(99), &call, &info, 0));
+
+        assert_ok!(InterCountryPayments::update_government_status(
+            RuntimeOrigin::root(),
+            fra,
+            GovernmentStatus::Revoked,
+        ));
+        assert_eq!(check.validate(&account(FRA + 4), &call, &info, 0), Err(InvalidTransaction::BadSigner.into()));
+    });
+}
+
+#[test]
+fn v6_migration_indexes_signatories() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let _ = SignatoryGovernments::<Test>::clear(u32::MAX, None);
+        StorageVersion::new(5).put::<InterCountryPayments>();
+
+        migrations::v6::BuildSignatoryIndex::<Test>::on_runtime_upgrade();
+
+        assert_eq!(InterCountryPayments::on_chain_storage_version(), 6);
+        assert!(signatories(FRA)
+            .iter()
+            .all(|s| InterCountryPayments::signatory_of(&s.account, &fra).is_some()));
+    });
+}
+
+#[test]
+fn v7_migration_moves_organisations_to_derived_accounts() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let old = account(200);
+        migrations::v7::OrganizationRegistry::<Test>::insert(
+            &old,
+            migrations::v7::OrganizationInfo {
+                organization_code: b"WHO".to_vec(),
+                organization_name: b"World Health Organization".to_vec(),
+                status: GovernmentStatus::Active,
+                registered_at: 0,
+            },
+        );
+        OrganizationToAccount::<Test>::insert(b"WHO".to_vec(), &old);
+        ContributionsReceived::<Test>::insert(&old, (b"FRA".to_vec(), ContributionType::MembershipFees), 500);
+        PaymentRegistry::<Test>::insert(0, stored_payment(0, &fra, &old, b""));
+        GovernmentPaymentHistory::<Test>::insert(&old, vec![0]);
+        StorageVersion::new(6).put::<InterCountryPayments>();
+
+        migrations::v7::DeriveOrganizationAccounts::<Test>::on_runtime_upgrade();
+
+        let who = InterCountryPayments::organization_account_id(b"WHO");
+        assert_eq!(InterCountryPayments::on_chain_storage_version(), 7);
+        assert!(InterCountryPayments::organization_info(&old).is_none());
+        let org = InterCountryPayments::organization_info(&who).unwrap();
+        assert!(org.authorized_signatories.is_empty());
+        assert_eq!(InterCountryPayments::organization_to_account(b"WHO".to_vec()), Some(who.clone()));
+        assert_eq!(
+            InterCountryPayments::get_organization_contributions(&who),
+            vec![(b"FRA".to_vec(), ContributionType::MembershipFees, 500)]
+        );
+        assert_eq!(InterCountryPayments::payment_info(0).unwrap().to_government, who);
+        assert_eq!(InterCountryPayments::government_payment_history(&who), Some(vec![0]));
+    });
+}
+
+#[test]
+fn v8_migration_indexes_unsettled_payments() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        for (payment_id, status) in [PaymentStatus::Completed, PaymentStatus::Frozen, PaymentStatus::Cancelled, PaymentStatus::Failed]
+            .into_iter()
+            .enumerate()
+        {
+            PaymentRegistry::<Test>::insert(
+                payment_id as u64,
+                InterCountryPayment { status, ..stored_payment(payment_id as u64, &fra, &deu, b"") },
+            );
+        }
+        StorageVersion::new(7).put::<InterCountryPayments>();
+
+        migrations::v8::IndexUnsettledPayments::<Test>::on_runtime_upgrade();
+
+        assert_eq!(InterCountryPayments::on_chain_storage_version(), 8);
+        for party in [&fra, &deu] {
+            let mut unsettled = InterCountryPayments::unsettled_payments(party).into_inner();
+            unsettled.sort();
+            assert_eq!(unsettled, vec![1, 3]);
+        }
+        assert_eq!(InterCountryPayments::payment_totals(&fra), (1_000, 0));
+        assert_eq!(InterCountryPayments::payment_totals(&deu), (0, 1_000));
+    });
+}
+
+#[test]
+fn signatory_calls_are_feeless_within_the_window() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+
+        // Failed calls do not use up the allowance
+        assert!(initiate_call(&fra, FRA, &deu, 0).is_err());
+
+        for _ in 0..3 {
+            let info = initiate_call(&fra, FRA, &deu, 10).unwrap();
+            assert_eq!(info.pays_fee, Pays::No);
+            assert_eq!(info.actual_weight, Some(Weight::from_parts(10_000, 0)));
+        }
+        assert_eq!(initiate_call(&fra, FRA, &deu, 10).unwrap().pays_fee, Pays::Yes);
+        assert_eq!(InterCountryPayments::feeless_calls(account(FRA)), FeelessWindow { window: 0, calls: 3 });
+
+        System::set_block_number(100);
+        assert_eq!(initiate_call(&fra, FRA, &deu, 10).unwrap().pays_fee, Pays::No);
+        assert_eq!(InterCountryPayments::feeless_calls(account(FRA)), FeelessWindow { window: 1, calls: 1 });
+    });
+}
+
+#[test]
+fn urgent_payments_get_priority() {
+    new_test_ext().execute_with(|| {
+        let fra = register(b"FRA", FRA);
+        let deu = register(b"DEU", DEU);
+        let initiation = |urgency_level| {
+            RuntimeCall::InterCountryPayments(crate::Call::initiate_payment {
+                from_government: fra.clone(),
+                to_government: deu.clone(),
+                amount: 100,
+                purpose: PaymentPurpose::EmergencyAssistance { disaster_reference: b"D-1".to_vec(), urgency_level },
+                reference: Vec::new(),
+                auto_execute: false,
+            })
+        };
+        let priority = |who: &AccountId, call: &RuntimeCall| {
+            PrioritizeUrgentPayments::<Test>::new().validate(who, call, &call.get_dispatch_info(), 0).unwrap().priority
+        };
+
+        assert_eq!(priority(&account(FRA), &initiation(UrgencyLevel::Critical)), 1_000);
+        assert_eq!(priority(&account(FRA), &initiation(UrgencyLevel::High)), 500);
+        assert_eq!(priority(&account(FRA), &initiation(UrgencyLevel::Low)), 0);
+        assert_eq!(priority(&account(FRA + 1), &initiation(UrgencyLevel::Critical)), 0);
+
+        // Releasers are boosted only for the call the payment is waiting for
+        let payment_id = InterCountryPayments::next_payment_id();
+        assert_ok!(initiation(UrgencyLevel::Critical).dispatch(RuntimeOrigin::signed(account(FRA))));
+        let execution = RuntimeCall::InterCountryPayments(crate::Call::execute_payment { payment_id });
+        let retry = RuntimeCall::InterCountryPayments(crate::Call::retry_payment { payment_id });
+        assert_eq!(priority(&account(FRA + 4), &execution), 0);
+
+        authorize(payment_id, FRA);
+        assert_eq!(priority(&account(FRA + 4), &execution), 1_000);
+        assert_eq!(priority(&account(FRA + 4), &retry), 0);
+
+        act(&deu, DEU, GovernmentAction::SetCounterpartyRule {
+            counterparty: fra.clone(),
+            rule: Some(CounterpartyRule::Deny),
+        });
+        execute(payment_id, FRA);
+        assert_eq!(status(payment_id), PaymentStatus::Failed);
+        assert_eq!(priority(&account(FRA + 4), &execution), 0);
+        assert_eq!(priority(&account(FRA + 4), &retry), 1_000);
+    });
+}